@@ -0,0 +1,136 @@
+use crate::error::{Result, ZecKitError};
+use bech32::primitives::decode::CheckedHrpstring;
+use bech32::{Bech32, Bech32m};
+
+/// Base58Check version prefixes shared by testnet and regtest
+const TRANSPARENT_P2PKH: [u8; 2] = [0x1d, 0x25];
+const TRANSPARENT_P2SH: [u8; 2] = [0x1c, 0xba];
+
+const SAPLING_HRP: &str = "zregtestsapling";
+const UNIFIED_HRP: &str = "uregtest";
+const TEX_HRP: &str = "texregtest";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressKind {
+    Transparent,
+    Sapling,
+    Unified,
+    Tex,
+}
+
+impl std::fmt::Display for AddressKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            AddressKind::Transparent => "transparent",
+            AddressKind::Sapling => "sapling",
+            AddressKind::Unified => "unified",
+            AddressKind::Tex => "tex",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Check that `address` is a well-formed regtest address before it is sent
+/// anywhere. This verifies the network prefix and checksum only; the faucet
+/// still does full decoding.
+pub fn validate_regtest_address(address: &str) -> Result<AddressKind> {
+    let invalid = |reason: &str| ZecKitError::InvalidAddress(format!("{}: {}", address, reason));
+
+    if address.starts_with('t') && !address.starts_with(TEX_HRP) {
+        let payload = bs58::decode(address)
+            .with_check(None)
+            .into_vec()
+            .map_err(|_| invalid("bad base58check encoding"))?;
+
+        return match payload.get(..2) {
+            Some(version) if version == TRANSPARENT_P2PKH || version == TRANSPARENT_P2SH => {
+                Ok(AddressKind::Transparent)
+            }
+            _ => Err(invalid("not a regtest transparent address")),
+        };
+    }
+
+    let (hrp, kind) = if let Ok(checked) = CheckedHrpstring::new::<Bech32m>(address) {
+        (checked.hrp().to_lowercase(), None)
+    } else if let Ok(checked) = CheckedHrpstring::new::<Bech32>(address) {
+        (checked.hrp().to_lowercase(), Some(AddressKind::Sapling))
+    } else {
+        return Err(invalid("bad bech32 encoding or checksum"));
+    };
+
+    // Sapling uses Bech32; unified and TEX addresses use Bech32m
+    match (hrp.as_str(), kind) {
+        (SAPLING_HRP, Some(AddressKind::Sapling)) => Ok(AddressKind::Sapling),
+        (UNIFIED_HRP, None) => Ok(AddressKind::Unified),
+        (TEX_HRP, None) => Ok(AddressKind::Tex),
+        _ => Err(invalid("not a regtest address")),
+    }
+}
+
+/// Regtest address paid by a transparent output script, if it is a standard
+/// P2PKH or P2SH script
+pub fn transparent_from_script(script: &[u8]) -> Option<String> {
+    match script {
+        [0x76, 0xa9, 0x14, hash @ .., 0x88, 0xac] if hash.len() == 20 => {
+            Some(encode_transparent(TRANSPARENT_P2PKH, hash))
+        }
+        [0xa9, 0x14, hash @ .., 0x87] if hash.len() == 20 => Some(encode_transparent(TRANSPARENT_P2SH, hash)),
+        _ => None,
+    }
+}
+
+/// Regtest P2PKH address of a 20-byte public key hash
+pub fn transparent_p2pkh(hash: &[u8]) -> String {
+    encode_transparent(TRANSPARENT_P2PKH, hash)
+}
+
+fn encode_transparent(version: [u8; 2], hash: &[u8]) -> String {
+    let mut payload = version.to_vec();
+    payload.extend_from_slice(hash);
+    bs58::encode(payload).with_check().into_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_regtest_addresses() {
+        assert_eq!(
+            validate_regtest_address("tmBsTi2xWTjUdEXnuTceL7fecEQKeWaPDJd").unwrap(),
+            AddressKind::Transparent
+        );
+        assert_eq!(
+            validate_regtest_address(
+                "uregtest1h8fnf3vrmswwj0r6nfvq24nxzmyjzaq5jvyxyc2afjtuze8tn93zjqt87kv9wm0ew4rkprpuphf08tc7f5nnd3j3kxnngyxf0cv9k9lc"
+            )
+            .unwrap(),
+            AddressKind::Unified
+        );
+    }
+
+    #[test]
+    fn rejects_other_networks_and_typos() {
+        // Mainnet transparent
+        assert!(validate_regtest_address("t1Hsc1LR8yKnbbe3twRp88p6vFfC5t7DLbs").is_err());
+        // Last character changed
+        assert!(validate_regtest_address("tmBsTi2xWTjUdEXnuTceL7fecEQKeWaPDJe").is_err());
+        assert!(validate_regtest_address("not_an_address").is_err());
+    }
+
+    #[test]
+    fn standard_scripts_map_to_addresses() {
+        let address = "tmBsTi2xWTjUdEXnuTceL7fecEQKeWaPDJd";
+        let hash = bs58::decode(address).with_check(None).into_vec().unwrap()[2..].to_vec();
+
+        let p2pkh = [&[0x76, 0xa9, 0x14][..], &hash, &[0x88, 0xac]].concat();
+        assert_eq!(transparent_from_script(&p2pkh).as_deref(), Some(address));
+
+        let p2sh = [&[0xa9, 0x14][..], &hash, &[0x87]].concat();
+        let p2sh_address = transparent_from_script(&p2sh).unwrap();
+        assert_eq!(validate_regtest_address(&p2sh_address).unwrap(), AddressKind::Transparent);
+        assert_ne!(p2sh_address, address);
+
+        assert_eq!(transparent_from_script(&[0x6a, 0x01, 0x00]), None);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::config::settings::CONFIG_FILE_NAME;
+use crate::error::Result;
+use std::path::Path;
+
+/// A file from this repository, compiled into the binary
+macro_rules! asset {
+    ($path:literal) => {
+        Asset {
+            path: $path,
+            contents: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../", $path)),
+        }
+    };
+}
+
+/// A project file `zeckit init` writes
+pub struct Asset {
+    /// Relative to the project root
+    pub path: &'static str,
+    pub contents: &'static str,
+}
+
+/// Everything `docker compose` needs to build and run the devnet: the compose
+/// file, service configs and Dockerfiles, and the faucet's build context
+pub const FILES: &[Asset] = &[
+    asset!("docker-compose.yml"),
+    asset!("docker/configs/faucet.env.example"),
+    asset!("docker/configs/zcash.conf"),
+    asset!("docker/configs/zebra.toml"),
+    asset!("docker/configs/zindexer.toml"),
+    asset!("docker/healthchecks/check-zebra.sh"),
+    asset!("docker/lightwalletd/Dockerfile"),
+    asset!("docker/lightwalletd/entrypoint.sh"),
+    asset!("docker/miner/Dockerfile"),
+    asset!("docker/miner/entrypoint.sh"),
+    asset!("docker/zaino/Dockerfile"),
+    asset!("docker/zaino/entrypoint.sh"),
+    asset!("docker/zebra/Dockerfile"),
+    asset!("docker/zebra/entrypoint.sh"),
+    asset!("docker/zingo/Dockerfile"),
+    asset!("docker/zingo/entrypoint.sh"),
+    asset!("zeckit-faucet/Cargo.toml"),
+    asset!("zeckit-faucet/Dockerfile"),
+    asset!("zeckit-faucet/src/api/faucet.rs"),
+    asset!("zeckit-faucet/src/api/health.rs"),
+    asset!("zeckit-faucet/src/api/mod.rs"),
+    asset!("zeckit-faucet/src/api/stats.rs"),
+    asset!("zeckit-faucet/src/api/wallet.rs"),
+    asset!("zeckit-faucet/src/config.rs"),
+    asset!("zeckit-faucet/src/error.rs"),
+    asset!("zeckit-faucet/src/main.rs"),
+    asset!("zeckit-faucet/src/validation/mod.rs"),
+    asset!("zeckit-faucet/src/validation/zebra_rpc.rs"),
+    asset!("zeckit-faucet/src/wallet/history.rs"),
+    asset!("zeckit-faucet/src/wallet/indexer.rs"),
+    asset!("zeckit-faucet/src/wallet/manager.rs"),
+    asset!("zeckit-faucet/src/wallet/mod.rs"),
+    asset!("zeckit-faucet/src/wallet/seed.rs"),
+];
+
+/// `zeckit.toml`, written from the documented example
+pub const CONFIG: Asset = Asset {
+    path: CONFIG_FILE_NAME,
+    contents: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../zeckit.toml.example")),
+};
+
+/// Write `asset` under `dir`; returns false if the file exists and `force` is off
+pub fn write(asset: &Asset, dir: &Path, force: bool) -> Result<bool> {
+    let path = dir.join(asset.path);
+    if path.exists() && !force {
+        return Ok(false);
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, asset.contents)?;
+
+    #[cfg(unix)]
+    if asset.path.ends_with(".sh") {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn files_under(dir: &Path, out: &mut Vec<PathBuf>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                files_under(&path, out);
+            } else {
+                out.push(path);
+            }
+        }
+    }
+
+    #[test]
+    fn embeds_every_devnet_file() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let mut files = Vec::new();
+        files_under(&root.join("docker"), &mut files);
+        files_under(&root.join("zeckit-faucet/src"), &mut files);
+
+        for file in files {
+            let relative = file.strip_prefix(root).unwrap().to_string_lossy().replace('\\', "/");
+            // Test sources aren't part of the faucet build
+            if relative.starts_with("zeckit-faucet/src/tests/") {
+                continue;
+            }
+            assert!(
+                FILES.iter().any(|asset| asset.path == relative),
+                "{} is not embedded in assets::FILES",
+                relative
+            );
+        }
+    }
+
+    #[test]
+    fn compose_file_parses_and_pins_every_image() {
+        let compose = FILES.iter().find(|asset| asset.path == "docker-compose.yml").unwrap();
+        let parsed: serde_yaml::Value = serde_yaml::from_str(compose.contents).unwrap();
+
+        let services = parsed["services"].as_mapping().unwrap();
+        assert!(!services.is_empty());
+        for (name, service) in services {
+            let image = service["image"].as_str().unwrap_or_default();
+            assert!(image.starts_with("zeckit/"), "{:?} has no zeckit/ image: {:?}", name, image);
+            assert!(service["build"]["image"].is_null(), "{:?} has image: inside build:", name);
+        }
+    }
+
+    #[test]
+    fn write_keeps_existing_files_unless_forced() {
+        let dir = tempfile::tempdir().unwrap();
+        let asset = Asset {
+            path: "docker/miner/entrypoint.sh",
+            contents: "#!/bin/bash\n",
+        };
+
+        assert!(write(&asset, dir.path(), false).unwrap());
+        std::fs::write(dir.path().join(asset.path), "edited").unwrap();
+        assert!(!write(&asset, dir.path(), false).unwrap());
+        assert_eq!(std::fs::read_to_string(dir.path().join(asset.path)).unwrap(), "edited");
+
+        assert!(write(&asset, dir.path(), true).unwrap());
+        assert_eq!(std::fs::read_to_string(dir.path().join(asset.path)).unwrap(), "#!/bin/bash\n");
+    }
+}
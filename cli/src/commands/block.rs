@@ -0,0 +1,61 @@
+use crate::config::Settings;
+use crate::error::Result;
+use crate::output;
+use crate::rpc::zebra::Block;
+use crate::rpc::ZebraRpc;
+use crate::tx::{self, Transaction};
+use colored::*;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct BlockReport {
+    #[serde(flatten)]
+    block: Block,
+    transactions: Vec<BlockTx>,
+}
+
+#[derive(Serialize)]
+struct BlockTx {
+    txid: String,
+    /// Absent if the transaction could not be fetched or decoded
+    #[serde(flatten)]
+    decoded: Option<Transaction>,
+}
+
+pub async fn execute(settings: &Settings, hash_or_height: &str) -> Result<()> {
+    let rpc = ZebraRpc::new(settings);
+    let block = rpc.get_block(hash_or_height).await?;
+    let (network, _) = super::tx::network(&rpc).await?;
+
+    result!("{} {}", "Block".bold(), format!("#{}", block.height).bold());
+    result!("  Hash:          {}", block.hash);
+    if let Some(previous) = &block.previous_block_hash {
+        result!("  Previous:      {}", previous);
+    }
+    if let Some(time) = block.time {
+        result!("  Time:          {} (unix)", time);
+    }
+    if let Some(size) = block.size {
+        result!("  Size:          {} bytes", size);
+    }
+    result!("  Confirmations: {}", block.confirmations);
+    result!("  Transactions:  {}", block.tx.len());
+
+    let mut transactions = Vec::new();
+    for txid in &block.tx {
+        let decoded = match rpc.get_raw_transaction(txid).await {
+            Ok(raw) => tx::decode_hex(&raw.hex, &network, block.height),
+            Err(e) => Err(e),
+        };
+        match &decoded {
+            Ok(decoded) => result!("    {}  {}", txid, super::tx::summary(decoded)),
+            Err(e) => result!("    {}  {}", txid, format!("({})", e).yellow()),
+        }
+        transactions.push(BlockTx {
+            txid: txid.clone(),
+            decoded: decoded.ok(),
+        });
+    }
+
+    output::emit(&BlockReport { block, transactions })
+}
@@ -0,0 +1,284 @@
+use crate::config::Settings;
+use crate::docker::compose::DockerCompose;
+use crate::error::{Result, ZecKitError};
+use crate::output;
+use crate::utils;
+use colored::*;
+use serde::Serialize;
+use std::path::Path;
+use std::process::Command;
+
+/// Below this much free disk space image builds and chain data will not fit
+const MIN_FREE_BYTES: u64 = 5 * 1024 * 1024 * 1024;
+/// First builds of every image need roughly this much
+const RECOMMENDED_FREE_BYTES: u64 = 20 * 1024 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Check {
+    name: String,
+    status: CheckStatus,
+    detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hint: Option<String>,
+}
+
+impl Check {
+    fn pass(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self { name: name.into(), status: CheckStatus::Pass, detail: detail.into(), hint: None }
+    }
+
+    fn warn(name: impl Into<String>, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self { name: name.into(), status: CheckStatus::Warn, detail: detail.into(), hint: Some(hint.into()) }
+    }
+
+    fn fail(name: impl Into<String>, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self { name: name.into(), status: CheckStatus::Fail, detail: detail.into(), hint: Some(hint.into()) }
+    }
+}
+
+#[derive(Serialize)]
+struct DoctorReport {
+    ok: bool,
+    checks: Vec<Check>,
+}
+
+pub async fn execute(settings: &Settings) -> Result<()> {
+    say!("{}", "ZecKit doctor".bold());
+    say!();
+
+    let checks = run_checks(settings)?;
+    for check in &checks {
+        print_check(check);
+    }
+
+    let failed = checks.iter().filter(|c| c.status == CheckStatus::Fail).count();
+    let warned = checks.iter().filter(|c| c.status == CheckStatus::Warn).count();
+    say!();
+    if failed == 0 {
+        result!("{}", format!("✓ Ready to run zeckit up ({} warning(s))", warned).green().bold());
+    }
+
+    output::emit(&DoctorReport { ok: failed == 0, checks })?;
+
+    if failed > 0 {
+        return Err(ZecKitError::Preflight(format!("{} check(s) failed", failed)));
+    }
+    Ok(())
+}
+
+/// Run the checks before `zeckit up`, printing only problems. Fails on any
+/// check that would make the devnet fail to start.
+pub fn preflight(settings: &Settings) -> Result<()> {
+    let checks = run_checks(settings)?;
+    let problems: Vec<&Check> = checks.iter().filter(|c| c.status != CheckStatus::Pass).collect();
+
+    for check in &problems {
+        print_check(check);
+    }
+    if !problems.is_empty() {
+        say!();
+    }
+
+    let failed: Vec<&str> = problems
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .map(|c| c.name.as_str())
+        .collect();
+    if !failed.is_empty() {
+        return Err(ZecKitError::Preflight(format!(
+            "{} (run zeckit doctor for details)",
+            failed.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+fn print_check(check: &Check) {
+    let mark = match check.status {
+        CheckStatus::Pass => "✓".green(),
+        CheckStatus::Warn => "!".yellow(),
+        CheckStatus::Fail => "✗".red(),
+    };
+    result!("  {} {:<26} {}", mark, check.name, check.detail);
+    if let Some(hint) = &check.hint {
+        result!("    {} {}", "→".dimmed(), hint.dimmed());
+    }
+}
+
+/// Every check, in the order a broken setup should be fixed
+pub fn run_checks(settings: &Settings) -> Result<Vec<Check>> {
+    let project_dir = utils::project_dir()?;
+    let mut checks = Vec::new();
+
+    let docker_ok = utils::check_docker() && utils::check_docker_daemon();
+    checks.push(check_docker());
+
+    let compose_ok = docker_ok && utils::check_docker_compose();
+    checks.push(check_compose(docker_ok, compose_ok));
+
+    let compose_file = project_dir.join("docker-compose.yml");
+    checks.push(check_file("docker-compose.yml", &compose_file));
+    checks.push(check_file("zebra.toml", &project_dir.join("docker/configs/zebra.toml")));
+
+    checks.push(check_disk(&project_dir));
+
+    let compose = DockerCompose::new(settings)?;
+    let running: Vec<String> = if compose_ok && compose_file.exists() {
+        compose
+            .ps()
+            .map(|containers| containers.into_iter().filter(|c| c.is_running()).map(|c| c.service).collect())
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    checks.extend(check_ports(settings, &running));
+
+    if compose_ok && compose_file.exists() {
+        checks.push(check_images(settings, &compose));
+    }
+
+    Ok(checks)
+}
+
+fn check_docker() -> Check {
+    const NAME: &str = "Docker daemon";
+
+    if !utils::check_docker() {
+        return Check::fail(NAME, "docker not found", "Install Docker: https://docs.docker.com/get-docker/");
+    }
+    if !utils::check_docker_daemon() {
+        return Check::fail(
+            NAME,
+            "not reachable",
+            "Start Docker Desktop or the daemon (sudo systemctl start docker), and check your user can access it",
+        );
+    }
+
+    let version = docker_output(&["version", "--format", "{{.Server.Version}}"]);
+    Check::pass(NAME, format!("running (server {})", version.as_deref().unwrap_or("unknown")))
+}
+
+fn check_compose(docker_ok: bool, compose_ok: bool) -> Check {
+    const NAME: &str = "Docker Compose v2";
+
+    if !docker_ok {
+        return Check::warn(NAME, "skipped", "Fix the Docker daemon first");
+    }
+    if !compose_ok {
+        return Check::fail(
+            NAME,
+            "`docker compose` not available",
+            "Install the Compose v2 plugin: https://docs.docker.com/compose/install/ (standalone docker-compose v1 is not supported)",
+        );
+    }
+
+    let version = docker_output(&["compose", "version", "--short"]);
+    Check::pass(NAME, version.unwrap_or_else(|| "available".into()))
+}
+
+fn check_file(name: &str, path: &Path) -> Check {
+    if path.is_file() {
+        Check::pass(name, path.display().to_string())
+    } else {
+        Check::fail(
+            name,
+            format!("not found at {}", path.display()),
+            "Run zeckit from a ZecKit project (or a directory below it), or create one with `zeckit init`",
+        )
+    }
+}
+
+fn check_disk(project_dir: &Path) -> Check {
+    const NAME: &str = "Disk space";
+
+    // Images and volumes live under Docker's root dir, which may be another filesystem
+    let docker_root = docker_output(&["info", "--format", "{{.DockerRootDir}}"])
+        .map(std::path::PathBuf::from)
+        .filter(|p| p.exists());
+    let path = docker_root.as_deref().unwrap_or(project_dir);
+
+    let Some(free) = utils::free_disk_space(path) else {
+        return Check::warn(NAME, "could not determine free space", format!("Make sure {} has at least 20 GB free", path.display()));
+    };
+
+    let detail = format!("{} free on {}", utils::format_bytes(free), path.display());
+    if free < MIN_FREE_BYTES {
+        Check::fail(NAME, detail, "Free up space, e.g. docker system prune (removes unused images and volumes)")
+    } else if free < RECOMMENDED_FREE_BYTES {
+        Check::warn(NAME, detail, "First image builds need about 20 GB; consider docker system prune")
+    } else {
+        Check::pass(NAME, detail)
+    }
+}
+
+/// A port counts as this devnet's only while a service publishing it is running;
+/// every other port is probed
+fn check_ports(settings: &Settings, running: &[String]) -> Vec<Check> {
+    let indexer = settings.backend.service().is_some();
+    let ports: [(&str, u16, bool, &[&str]); 4] = [
+        ("Zebra RPC", settings.ports.zebra_rpc, true, &["zebra"]),
+        ("Zebra P2P", settings.ports.zebra_p2p, true, &["zebra"]),
+        ("Backend gRPC", settings.ports.backend, indexer, &["lightwalletd", "zaino"]),
+        ("Faucet API", settings.ports.faucet, indexer, &["faucet-lwd", "faucet-zaino"]),
+    ];
+
+    ports
+        .iter()
+        .map(|&(label, port, needed, services)| {
+            let name = format!("Port {} ({})", port, label);
+            if let Some(owner) = services.iter().find(|s| running.iter().any(|r| r == *s)) {
+                Check::pass(name, format!("in use by this devnet ({})", owner))
+            } else if utils::port_is_free(settings.host, port) {
+                Check::pass(name, "free")
+            } else {
+                let hint = format!(
+                    "Stop whatever listens on {} (lsof -i :{}) or change [ports] in zeckit.toml",
+                    port, port
+                );
+                if needed {
+                    Check::fail(name, "in use", hint)
+                } else {
+                    Check::warn(name, "in use (only needed with --backend lwd|zaino)", hint)
+                }
+            }
+        })
+        .collect()
+}
+
+fn check_images(settings: &Settings, compose: &DockerCompose) -> Check {
+    const NAME: &str = "Images";
+
+    let images = match compose.images(settings.backend.profile()) {
+        Ok(images) => images,
+        Err(e) => {
+            return Check::fail(NAME, "docker-compose.yml is invalid", format!("docker compose config: {}", e.to_string().trim()));
+        }
+    };
+
+    let missing: Vec<&str> = images.iter().filter(|(_, exists)| !exists).map(|(i, _)| i.as_str()).collect();
+    if missing.is_empty() {
+        Check::pass(NAME, format!("all {} built", images.len()))
+    } else {
+        Check::warn(
+            NAME,
+            format!("{} of {} not built: {}", missing.len(), images.len(), missing.join(", ")),
+            "zeckit up builds them on first start (10-20 minutes), or load a bundle with: zeckit images load <file>",
+        )
+    }
+}
+
+fn docker_output(args: &[&str]) -> Option<String> {
+    let output = Command::new("docker").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string()).filter(|s| !s.is_empty())
+}
@@ -1,25 +1,37 @@
+use crate::config::devnet::Devnet;
+use crate::config::Settings;
 use crate::docker::compose::DockerCompose;
 use crate::error::Result;
+use crate::output;
 use colored::*;
+use serde_json::json;
 
-pub async fn execute(purge: bool) -> Result<()> {
-    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
-    println!("{}", "  ZecKit - Stopping Devnet".cyan().bold());
-    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
-    println!();
+pub async fn execute(settings: &Settings, purge: bool) -> Result<()> {
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!("{}", "  ZecKit - Stopping Devnet".cyan().bold());
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!();
     
-    let compose = DockerCompose::new()?;
+    let compose = DockerCompose::new(settings)?;
     
-    println!("{} Stopping services...", "🛑".yellow());
+    say!("{} Stopping services...", "🛑".yellow());
     compose.down(purge)?;
     
     if purge {
-        println!("{} Volumes removed (fresh start on next up)", "✓".green());
+        say!("{} Volumes removed (fresh start on next up)", "✓".green());
+        
+        // A purged devnet has nothing left to come back to, so release its ports
+        if let Some(name) = &settings.name {
+            Devnet::remove(name)?;
+        }
     }
     
-    println!();
-    println!("{}", "✓ Devnet stopped successfully".green().bold());
-    println!();
+    say!();
+    result!("{}", "✓ Devnet stopped successfully".green().bold());
+    say!();
     
-    Ok(())
+    output::emit(&json!({
+        "stopped": true,
+        "volumes_removed": purge
+    }))
 }
\ No newline at end of file
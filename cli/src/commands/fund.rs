@@ -0,0 +1,78 @@
+use crate::address::validate_regtest_address;
+use crate::config::Settings;
+use crate::error::{Result, ZecKitError};
+use crate::rpc::faucet::FundResponse;
+use crate::rpc::{FaucetApi, ZebraRpc};
+use crate::output;
+use colored::*;
+use serde::Serialize;
+use crate::wait;
+use tokio::time::Duration;
+
+pub async fn execute(
+    settings: &Settings,
+    address: String,
+    amount: Option<f64>,
+    memo: Option<String>,
+    wait_confirmations: u64,
+) -> Result<()> {
+    let kind = validate_regtest_address(&address)?;
+    say!("Requesting funds for {} address {}...", kind, address);
+
+    let faucet = FaucetApi::new(settings);
+    let funded = faucet.request_funds(&address, amount, memo.as_deref()).await?;
+
+    result!("{} Sent {} ZEC to {}", "✓".green(), funded.amount, funded.address);
+    result!("  TXID: {}", funded.txid);
+    result!("  Faucet balance: {} ZEC", funded.new_balance);
+
+    let confirmations = if wait_confirmations > 0 {
+        wait_for_confirmations(settings, &funded.txid, wait_confirmations).await?
+    } else {
+        0
+    };
+
+    output::emit(&FundReport {
+        address_type: kind.to_string(),
+        funded,
+        confirmations,
+    })
+}
+
+#[derive(Serialize)]
+struct FundReport {
+    #[serde(flatten)]
+    funded: FundResponse,
+    address_type: String,
+    confirmations: u64,
+}
+
+/// Mine one block at a time until the transaction has `target` confirmations.
+/// Mining starts only once the transaction has reached Zebra's mempool.
+pub(crate) async fn wait_for_confirmations(settings: &Settings, txid: &str, target: u64) -> Result<u64> {
+    let rpc = ZebraRpc::new(settings);
+
+    say!();
+    say!("Mining until {} confirmation(s)...", target);
+
+    wait::transaction_mined(&rpc, txid, Duration::from_secs(settings.timeouts.tx_secs)).await?;
+
+    // Every further block adds one confirmation
+    let mut confirmations = rpc.get_transaction_confirmations(txid).await?.unwrap_or(0);
+    if confirmations < target {
+        let blocks = u32::try_from(target - confirmations).map_err(|_| {
+            ZecKitError::Config(format!("Cannot mine {} blocks; at most {} per call", target - confirmations, u32::MAX))
+        })?;
+        rpc.generate(blocks).await?;
+        confirmations = rpc.get_transaction_confirmations(txid).await?.unwrap_or(0);
+    }
+    if confirmations < target {
+        return Err(ZecKitError::ServiceNotReady(format!(
+            "Transaction {} has {} of {} confirmations after mining",
+            txid, confirmations, target
+        )));
+    }
+
+    result!("{} Transaction confirmed ({} confirmation(s))", "✓".green(), confirmations);
+    Ok(confirmations)
+}
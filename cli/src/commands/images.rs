@@ -0,0 +1,259 @@
+use crate::config::{Backend, Settings};
+use crate::docker::compose::{DockerCompose, PROFILES};
+use crate::docker::image::{self, ImageInfo};
+use crate::docker::volume::HELPER_IMAGE;
+use crate::error::{Result, ZecKitError};
+use crate::output;
+use clap::Subcommand;
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Scratch space for assembling or unpacking a bundle, relative to the project root
+const STAGING_DIR: &str = ".zeckit/images/.staging";
+const MANIFEST_FILE: &str = "manifest.json";
+const IMAGES_FILE: &str = "images.tar";
+
+#[derive(Subcommand)]
+pub enum ImagesAction {
+    /// Export the devnet images and a manifest to a bundle for offline use
+    Save {
+        /// Bundle to write, e.g. zeckit-images.tar.gz
+        file: PathBuf,
+
+        /// Only the images this backend needs [default: every profile]
+        #[arg(short, long)]
+        backend: Option<Backend>,
+    },
+    /// Import a bundle written by `zeckit images save`
+    Load {
+        /// Bundle to read
+        file: PathBuf,
+    },
+}
+
+/// Stored as `manifest.json` at the root of every bundle
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    zeckit_version: String,
+    created_at: u64,
+    profiles: Vec<String>,
+    images: Vec<BundledImage>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BundledImage {
+    /// Compose services run from this image; empty for the helper image snapshots use
+    services: Vec<String>,
+    image: String,
+    #[serde(flatten)]
+    info: ImageInfo,
+}
+
+#[derive(Serialize)]
+struct BundleReport {
+    path: PathBuf,
+    #[serde(flatten)]
+    manifest: Manifest,
+}
+
+pub async fn execute(settings: &Settings, action: ImagesAction) -> Result<()> {
+    match action {
+        ImagesAction::Save { file, backend } => save(settings, &file, backend),
+        ImagesAction::Load { file } => load(&file),
+    }
+}
+
+fn save(settings: &Settings, path: &Path, backend: Option<Backend>) -> Result<()> {
+    let compose = DockerCompose::new(settings)?;
+
+    // Node-only devnets use the services outside every profile
+    let profiles: Vec<Option<&str>> = match backend {
+        Some(backend) => vec![backend.profile()],
+        None => PROFILES.iter().map(|p| Some(*p)).collect(),
+    };
+
+    // Services sharing a build (both faucets) share an image
+    let mut by_image: Vec<(String, Vec<String>)> = Vec::new();
+    for profile in &profiles {
+        for (service, name) in compose.service_images(*profile)? {
+            match by_image.iter_mut().find(|(image, _)| *image == name) {
+                Some((_, services)) if services.contains(&service) => {}
+                Some((_, services)) => services.push(service),
+                None => by_image.push((name, vec![service])),
+            }
+        }
+    }
+    by_image.sort();
+
+    let mut images = Vec::new();
+    let mut missing = Vec::new();
+    for (name, mut services) in by_image {
+        services.sort();
+        match image::inspect(&name) {
+            Some(info) => images.push(BundledImage { services, image: name, info }),
+            None => missing.push(name),
+        }
+    }
+    if !missing.is_empty() {
+        return Err(ZecKitError::Docker(format!(
+            "Image(s) not built: {} (run zeckit up{} first)",
+            missing.join(", "),
+            backend.map(|b| format!(" --backend {}", b)).unwrap_or_default()
+        )));
+    }
+    match image::inspect(HELPER_IMAGE) {
+        Some(info) => images.push(BundledImage {
+            services: Vec::new(),
+            image: HELPER_IMAGE.to_string(),
+            info,
+        }),
+        None => say!("{} {} not found; snapshots will need network to pull it", "⚠".yellow(), HELPER_IMAGE),
+    }
+
+    let manifest = Manifest {
+        zeckit_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        profiles: profiles.iter().flatten().map(|p| p.to_string()).collect(),
+        images,
+    };
+
+    say!("{} Saving {} image(s)...", "📦".cyan(), manifest.images.len());
+    for bundled in &manifest.images {
+        say!("  {:<32} {}", bundled.image, short_id(&bundled.info.id));
+    }
+
+    let staging = staging_dir()?;
+    let result = write_bundle(path, &staging, &manifest);
+    let _ = fs::remove_dir_all(&staging);
+    result?;
+
+    let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    say!();
+    result!("{} Bundle saved to {} ({})", "✓".green(), path.display(), crate::utils::format_bytes(size));
+    say!("  Load it with: zeckit images load {}", path.display());
+    say!();
+
+    output::emit(&BundleReport {
+        path: path.to_path_buf(),
+        manifest,
+    })
+}
+
+fn write_bundle(path: &Path, staging: &Path, manifest: &Manifest) -> Result<()> {
+    let names: Vec<String> = manifest.images.iter().map(|i| i.image.clone()).collect();
+    image::save(&names, &staging.join(IMAGES_FILE))?;
+    fs::write(staging.join(MANIFEST_FILE), serde_json::to_string_pretty(manifest)?)?;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    tar(&[
+        "czf".as_ref(),
+        path.as_os_str(),
+        "-C".as_ref(),
+        staging.as_os_str(),
+        ".".as_ref(),
+    ])?;
+
+    Ok(())
+}
+
+fn load(path: &Path) -> Result<()> {
+    if !path.is_file() {
+        return Err(ZecKitError::Docker(format!("No image bundle at {}", path.display())));
+    }
+
+    let staging = staging_dir()?;
+    let result = load_from(path, &staging);
+    let _ = fs::remove_dir_all(&staging);
+    let manifest = result?;
+
+    if manifest.zeckit_version != env!("CARGO_PKG_VERSION") {
+        say!(
+            "{} Bundle was saved by zeckit {} (this is {}); its images may not match this docker-compose.yml",
+            "⚠".yellow(),
+            manifest.zeckit_version,
+            env!("CARGO_PKG_VERSION")
+        );
+    }
+
+    say!();
+    result!("{} Loaded {} image(s) from {}", "✓".green(), manifest.images.len(), path.display());
+    for bundled in &manifest.images {
+        let version = bundled.info.version.as_deref().map(|v| format!(" ({})", v)).unwrap_or_default();
+        result!("  {:<32} {}{}", bundled.image, short_id(&bundled.info.id), version);
+    }
+    say!("  Start without building: zeckit up --offline");
+    say!();
+
+    output::emit(&BundleReport {
+        path: path.to_path_buf(),
+        manifest,
+    })
+}
+
+fn load_from(path: &Path, staging: &Path) -> Result<Manifest> {
+    tar(&[
+        "xzf".as_ref(),
+        path.as_os_str(),
+        "-C".as_ref(),
+        staging.as_os_str(),
+    ])?;
+
+    let manifest: Manifest = serde_json::from_str(
+        &fs::read_to_string(staging.join(MANIFEST_FILE))
+            .map_err(|_| ZecKitError::Docker(format!("{} is not an image bundle (no manifest)", path.display())))?,
+    )?;
+
+    say!("{} Loading {} image(s)...", "📦".cyan(), manifest.images.len());
+    image::load(&staging.join(IMAGES_FILE))?;
+
+    for bundled in &manifest.images {
+        match image::inspect(&bundled.image) {
+            Some(info) if info.id == bundled.info.id => {}
+            Some(info) => {
+                return Err(ZecKitError::Docker(format!(
+                    "{} loaded as {} but the manifest records {}",
+                    bundled.image, info.id, bundled.info.id
+                )))
+            }
+            None => return Err(ZecKitError::Docker(format!("{} is missing from the bundle", bundled.image))),
+        }
+    }
+
+    Ok(manifest)
+}
+
+/// `sha256:0123456789ab...` shortened to the 12 hex digits docker shows
+fn short_id(id: &str) -> &str {
+    let hex = id.strip_prefix("sha256:").unwrap_or(id);
+    &hex[..hex.len().min(12)]
+}
+
+/// Scratch directory under the project, emptied first
+fn staging_dir() -> Result<PathBuf> {
+    let dir = crate::utils::project_dir()?.join(STAGING_DIR);
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn tar(args: &[&std::ffi::OsStr]) -> Result<()> {
+    let output = Command::new("tar").args(args).output()?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(ZecKitError::Docker(format!("tar failed: {}", error.trim())));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::assets;
+use crate::error::Result;
+use crate::output;
+use colored::*;
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[derive(Serialize)]
+struct InitReport {
+    dir: PathBuf,
+    written: Vec<&'static str>,
+    /// Already present and left untouched (use --force to overwrite)
+    skipped: Vec<&'static str>,
+}
+
+pub async fn execute(dir: Option<PathBuf>, force: bool) -> Result<()> {
+    let dir = match dir {
+        Some(dir) => dir,
+        None => std::env::current_dir()?,
+    };
+    std::fs::create_dir_all(&dir)?;
+
+    say!("{} {}", "Scaffolding ZecKit devnet in".bold(), dir.display());
+
+    let mut written = Vec::new();
+    let mut skipped = Vec::new();
+    for asset in assets::FILES.iter().chain([&assets::CONFIG]) {
+        if assets::write(asset, &dir, force)? {
+            result!("  {} {}", "✓".green(), asset.path);
+            written.push(asset.path);
+        } else {
+            result!("  {} {} {}", "-".dimmed(), asset.path, "(exists, kept)".dimmed());
+            skipped.push(asset.path);
+        }
+    }
+
+    say!();
+    if !skipped.is_empty() {
+        say!("Kept {} existing file(s); rerun with --force to overwrite them.", skipped.len());
+    }
+    say!("Next: zeckit doctor && zeckit up --backend zaino");
+    say!("zeckit finds this project from {} or any directory below it.", dir.display());
+
+    output::emit(&InitReport { dir, written, skipped })
+}
@@ -0,0 +1,87 @@
+use crate::config::devnet::Devnet;
+use crate::config::{Backend, Settings};
+use crate::docker::compose::DockerCompose;
+use crate::error::Result;
+use crate::output;
+use colored::*;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct DevnetEntry {
+    /// `None` for the unnamed default devnet
+    name: Option<String>,
+    backend: Backend,
+    running: usize,
+    containers: usize,
+    zebra_rpc_url: String,
+    faucet_api_url: Option<String>,
+    backend_url: Option<String>,
+}
+
+pub async fn execute(settings: &Settings) -> Result<()> {
+    let mut entries = Vec::new();
+
+    // The default devnet has no registry record; only list it when it has containers
+    let mut default = settings.clone();
+    default.name = None;
+    let entry = describe(&default, DockerCompose::new(&default)?);
+    if entry.containers > 0 {
+        entries.push(entry);
+    }
+
+    for devnet in Devnet::list()? {
+        let mut named = settings.clone();
+        named.name = Some(devnet.name.clone());
+        named.backend = devnet.backend;
+        named.ports = devnet.ports.clone();
+
+        let compose = DockerCompose::new(&named)?.in_dir(&devnet.project_dir);
+        entries.push(describe(&named, compose));
+    }
+
+    if entries.is_empty() {
+        result!("No devnets (start one with: zeckit up --name <devnet>)");
+    } else {
+        result!(
+            "{}",
+            format!(
+                "  {:<16} {:<8} {:<10} {:<24} {:<24} {}",
+                "NAME", "BACKEND", "STATE", "ZEBRA RPC", "FAUCET", "INDEXER"
+            )
+            .bold()
+        );
+        for entry in &entries {
+            let state = if entry.running > 0 {
+                format!("{}/{} up", entry.running, entry.containers).green()
+            } else {
+                "stopped".red()
+            };
+            result!(
+                "  {:<16} {:<8} {:<10} {:<24} {:<24} {}",
+                entry.name.as_deref().unwrap_or("(default)"),
+                entry.backend.to_string(),
+                state,
+                entry.zebra_rpc_url,
+                entry.faucet_api_url.as_deref().unwrap_or("-"),
+                entry.backend_url.as_deref().unwrap_or("-"),
+            );
+        }
+    }
+
+    output::emit(&entries)
+}
+
+fn describe(settings: &Settings, compose: DockerCompose) -> DevnetEntry {
+    // A devnet whose project directory or docker daemon is gone shows up as stopped
+    let containers = compose.ps().unwrap_or_default();
+
+    DevnetEntry {
+        name: settings.name.clone(),
+        backend: settings.backend,
+        running: containers.iter().filter(|c| c.is_running()).count(),
+        containers: containers.len(),
+        zebra_rpc_url: settings.zebra_rpc_url(),
+        faucet_api_url: settings.backend.has_faucet().then(|| settings.faucet_api_url()),
+        backend_url: settings.backend.service().map(|_| settings.backend_url()),
+    }
+}
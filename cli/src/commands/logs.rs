@@ -0,0 +1,111 @@
+use crate::config::Settings;
+use crate::docker::compose::{DockerCompose, LogOptions};
+use crate::error::{Result, ZecKitError};
+use crate::output;
+use colored::*;
+use regex::Regex;
+use serde::Serialize;
+use std::io::{BufRead, BufReader};
+
+/// Friendly names accepted on the command line, mapped to compose services
+const SERVICE_ALIASES: &[(&str, &[&str])] = &[
+    ("zebra", &["zebra"]),
+    ("lightwalletd", &["lightwalletd"]),
+    ("lwd", &["lightwalletd"]),
+    ("zaino", &["zaino"]),
+    ("backend", &["lightwalletd", "zaino"]),
+    ("faucet", &["faucet-lwd", "faucet-zaino"]),
+    ("miner", &["miner"]),
+];
+
+/// One log line, as printed in JSON mode
+#[derive(Serialize)]
+struct LogLine<'a> {
+    service: Option<&'a str>,
+    message: &'a str,
+}
+
+pub async fn execute(
+    settings: &Settings,
+    service: Option<String>,
+    options: LogOptions,
+    grep: Option<String>,
+) -> Result<()> {
+    let compose = DockerCompose::new(settings)?;
+
+    let services: Vec<&str> = match service.as_deref() {
+        None => Vec::new(), // every service
+        Some(name) => SERVICE_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == name)
+            .map(|(_, services)| services.to_vec())
+            .ok_or_else(|| {
+                let known: Vec<&str> = SERVICE_ALIASES.iter().map(|(alias, _)| *alias).collect();
+                ZecKitError::Config(format!(
+                    "Unknown service: {}. Use one of: {}",
+                    name,
+                    known.join(", ")
+                ))
+            })?,
+    };
+
+    let pattern = grep
+        .map(|p| Regex::new(&p).map_err(|e| ZecKitError::Config(format!("Invalid --grep pattern: {}", e))))
+        .transpose()?;
+
+    let mut child = compose.logs_stream(&services, &options)?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| ZecKitError::Docker("No output from docker compose logs".into()))?;
+
+    for line in BufReader::new(stdout).lines() {
+        let line = line?;
+        let (name, message) = split_prefix(&line);
+
+        if let Some(re) = &pattern {
+            if !re.is_match(message) {
+                continue;
+            }
+        }
+
+        if output::is_json() {
+            output::emit_line(&LogLine { service: name, message })?;
+            continue;
+        }
+        match name {
+            Some(name) => result!("{} {}", colorize(name), message),
+            None => result!("{}", message),
+        }
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(ZecKitError::Docker("docker compose logs failed".into()));
+    }
+
+    Ok(())
+}
+
+/// Split compose's `service  | message` prefix off a log line
+fn split_prefix(line: &str) -> (Option<&str>, &str) {
+    match line.split_once(" | ") {
+        Some((name, message)) => (Some(name.trim()), message),
+        None => (None, line),
+    }
+}
+
+fn colorize(name: &str) -> ColoredString {
+    let padded = format!("{:<13}|", name);
+    if name.starts_with("zebra") {
+        padded.cyan()
+    } else if name.starts_with("lightwalletd") || name.starts_with("zaino") {
+        padded.magenta()
+    } else if name.starts_with("faucet") {
+        padded.green()
+    } else if name.starts_with("miner") {
+        padded.yellow()
+    } else {
+        padded.normal()
+    }
+}
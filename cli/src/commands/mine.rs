@@ -0,0 +1,84 @@
+use crate::config::Settings;
+use crate::error::{Result, ZecKitError};
+use crate::output;
+use crate::rpc::{FaucetApi, ZebraRpc};
+use crate::wait;
+use colored::*;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Serialize)]
+struct MineReport {
+    start_height: u64,
+    tip: u64,
+    blocks: Vec<MinedBlock>,
+    wallet_synced: bool,
+}
+
+#[derive(Serialize)]
+struct MinedBlock {
+    height: u64,
+    hash: String,
+}
+
+pub async fn execute(
+    settings: &Settings,
+    count: Option<u32>,
+    to_height: Option<u64>,
+    wait_sync: bool,
+) -> Result<()> {
+    let rpc = ZebraRpc::new(settings);
+    let start_height = rpc.get_block_count().await?;
+
+    let count = match (count, to_height) {
+        (Some(count), _) => count,
+        (None, Some(target)) if target <= start_height => {
+            result!("Chain is already at height {} (target {})", start_height, target);
+            return output::emit(&MineReport {
+                start_height,
+                tip: start_height,
+                blocks: Vec::new(),
+                wallet_synced: false,
+            });
+        }
+        (None, Some(target)) => u32::try_from(target - start_height)
+            .map_err(|_| ZecKitError::Config(format!("Target height {} is too far ahead", target)))?,
+        (None, None) => {
+            return Err(ZecKitError::Config("Specify a block count or --to-height".into()));
+        }
+    };
+
+    say!("Mining {} block(s) from height {}...", count, start_height);
+
+    // One block per call so every hash can be reported as soon as it exists
+    let mut blocks = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let hash = rpc
+            .generate(1)
+            .await?
+            .pop()
+            .ok_or_else(|| ZecKitError::HealthCheck("generate returned no block hash".into()))?;
+        let height = rpc.get_block_height(&hash).await?;
+
+        result!("  {} {} {}", "✓".green(), format!("#{}", height).bold(), hash);
+        blocks.push(MinedBlock { height, hash });
+    }
+
+    let tip = rpc.get_block_count().await?;
+    say!();
+    result!("{}", format!("✓ Mined {} block(s), tip is now {}", count, tip).green().bold());
+
+    if wait_sync {
+        say!("Waiting for faucet wallet to sync...");
+        let timeout = Duration::from_secs(settings.timeouts.sync_secs);
+        let synced = wait::wallet_synced(&FaucetApi::new(settings), &rpc, timeout).await?;
+        result!("{}", format!("✓ Faucet wallet synced to {}", synced).green());
+    }
+
+    output::emit(&MineReport {
+        start_height,
+        tip,
+        blocks,
+        wallet_synced: wait_sync,
+    })
+}
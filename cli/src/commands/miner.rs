@@ -0,0 +1,128 @@
+use crate::config::Settings;
+use crate::docker::compose::DockerCompose;
+use crate::error::{Result, ZecKitError};
+use crate::rpc::ZebraRpc;
+use clap::Subcommand;
+use crate::output;
+use colored::*;
+use serde::Serialize;
+
+const MINER_SERVICE: &str = "miner";
+const INTERVAL_FILE: &str = "/var/miner/interval";
+
+#[derive(Subcommand)]
+pub enum MinerAction {
+    /// Start the background miner container
+    Start,
+    /// Stop the background miner container
+    Stop,
+    /// Show whether the miner is running and at what rate
+    Status,
+    /// Change the mining interval without restarting the devnet
+    Rate {
+        /// Seconds between blocks
+        secs: u64,
+    },
+}
+
+#[derive(Serialize)]
+struct MinerReport {
+    running: bool,
+    interval_secs: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<u64>,
+}
+
+pub async fn execute(settings: &Settings, action: MinerAction) -> Result<()> {
+    let compose = DockerCompose::new(settings)?;
+
+    match action {
+        MinerAction::Start => {
+            if compose.is_service_running(MINER_SERVICE) {
+                result!("{} Miner already running", "✓".green());
+            } else {
+                compose.start_service(MINER_SERVICE)?;
+                result!("{} Miner started (1 block every {}s)", "✓".green(), current_interval(&compose, settings));
+            }
+        }
+        MinerAction::Stop => {
+            compose.stop_service(MINER_SERVICE)?;
+            result!("{} Miner stopped", "✓".green());
+        }
+        MinerAction::Status => {
+            return print_status(&compose, settings).await;
+        }
+        MinerAction::Rate { secs } => {
+            if secs == 0 {
+                return Err(ZecKitError::Config("Mining interval must be at least 1 second".into()));
+            }
+            if !compose.is_service_running(MINER_SERVICE) {
+                return Err(ZecKitError::ServiceNotReady(
+                    "Miner is not running (start it with: zeckit miner start)".into(),
+                ));
+            }
+
+            // The miner re-reads this file before every block, so no restart is needed
+            compose.exec(
+                MINER_SERVICE,
+                &["sh", "-c", &format!("echo {} > {}", secs, INTERVAL_FILE)],
+            )?;
+            result!("{} Miner now produces 1 block every {}s", "✓".green(), secs);
+        }
+    }
+
+    let running = compose.is_service_running(MINER_SERVICE);
+    output::emit(&MinerReport {
+        running,
+        interval_secs: current_interval(&compose, settings),
+        height: None,
+    })
+}
+
+/// Interval set with `zeckit miner rate`, falling back to the configured one
+fn current_interval(compose: &DockerCompose, settings: &Settings) -> u64 {
+    compose
+        .exec(MINER_SERVICE, &["cat", INTERVAL_FILE])
+        .ok()
+        .and_then(|s| s.trim().parse().ok())
+        .unwrap_or(settings.mining.interval_secs)
+}
+
+async fn print_status(compose: &DockerCompose, settings: &Settings) -> Result<()> {
+    let interval_secs = current_interval(compose, settings);
+    let height = ZebraRpc::new(settings).get_block_count().await.ok();
+
+    if !compose.is_service_running(MINER_SERVICE) {
+        result!("  {} {} - Stopped", "✗".red(), "Miner".bold());
+        return output::emit(&MinerReport {
+            running: false,
+            interval_secs,
+            height,
+        });
+    }
+
+    result!(
+        "  {} {} - Running (1 block every {}s)",
+        "✓".green(),
+        "Miner".bold(),
+        interval_secs
+    );
+
+    if let Some(height) = height {
+        result!("    Chain height: {}", height);
+    }
+
+    if let Some(last) = compose
+        .logs(MINER_SERVICE, 1)
+        .ok()
+        .and_then(|lines| lines.into_iter().last())
+    {
+        result!("    Last attempt: {}", last.trim());
+    }
+
+    output::emit(&MinerReport {
+        running: true,
+        interval_secs,
+        height,
+    })
+}
@@ -1,4 +1,18 @@
 pub mod up;
+pub mod init;
 pub mod down;
+pub mod doctor;
 pub mod status;
-pub mod test;
\ No newline at end of file
+pub mod list;
+pub mod test;
+pub mod mine;
+pub mod miner;
+pub mod logs;
+pub mod fund;
+pub mod wallet;
+pub mod snapshot;
+pub mod images;
+pub mod seed;
+pub mod rpc;
+pub mod block;
+pub mod tx;
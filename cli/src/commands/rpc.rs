@@ -0,0 +1,51 @@
+use crate::config::Settings;
+use crate::error::Result;
+use crate::output;
+use crate::rpc::ZebraRpc;
+use serde_json::Value;
+
+pub async fn execute(settings: &Settings, method: &str, params: &[String]) -> Result<()> {
+    let params: Vec<Value> = params.iter().map(|p| parse_param(p)).collect();
+    let result = ZebraRpc::new(settings).call(method, Value::Array(params)).await?;
+
+    if output::is_json() {
+        return output::emit(&result);
+    }
+
+    // Like zcash-cli: bare strings (hashes, hex) unquoted, everything else as JSON
+    match &result {
+        Value::String(s) => println!("{}", s),
+        Value::Null => {}
+        other => println!("{}", serde_json::to_string_pretty(other)?),
+    }
+    Ok(())
+}
+
+/// Numbers, booleans, `null`, arrays and objects are passed as JSON; anything
+/// else (hashes, addresses) as a string. Quote a value to force a string: '"101"'
+fn parse_param(param: &str) -> Value {
+    serde_json::from_str(param).unwrap_or_else(|_| Value::String(param.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn params_are_parsed_as_json_where_possible() {
+        assert_eq!(parse_param("101"), json!(101));
+        assert_eq!(parse_param("-1"), json!(-1));
+        assert_eq!(parse_param("true"), json!(true));
+        assert_eq!(parse_param(r#"{"verbose": 1}"#), json!({ "verbose": 1 }));
+        assert_eq!(parse_param(r#"["a", 2]"#), json!(["a", 2]));
+        assert_eq!(parse_param(r#""101""#), json!("101"));
+    }
+
+    #[test]
+    fn other_params_are_strings() {
+        let hash = "029f11d80ef9765602235e1bc9727e3eb6ba20839319f761fee920d63401e327";
+        assert_eq!(parse_param(hash), json!(hash));
+        assert_eq!(parse_param("tmBsTi2xWTjUdEXnuTceL7fecEQKeWaPDJd"), json!("tmBsTi2xWTjUdEXnuTceL7fecEQKeWaPDJd"));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::error::{Result, ZecKitError};
+use crate::output;
+use crate::seed;
+use clap::Subcommand;
+use colored::*;
+use serde::Serialize;
+
+#[derive(Subcommand)]
+pub enum SeedAction {
+    /// Generate a fresh faucet mnemonic and show the address Zebra will mine to
+    Generate {
+        /// Number of words: 12, 15, 18, 21 or 24
+        #[arg(short, long, default_value_t = 24)]
+        words: usize,
+
+        /// BIP-39 passphrase the faucet will be configured with
+        #[arg(long)]
+        passphrase: Option<String>,
+    },
+}
+
+#[derive(Serialize)]
+struct GeneratedSeed {
+    mnemonic: String,
+    miner_address: String,
+}
+
+pub async fn execute(action: SeedAction) -> Result<()> {
+    match action {
+        SeedAction::Generate { words, passphrase } => {
+            if !seed::WORD_COUNTS.contains(&words) {
+                return Err(ZecKitError::Config(format!(
+                    "Invalid word count {} (use 12, 15, 18, 21 or 24)",
+                    words
+                )));
+            }
+
+            let mnemonic = seed::generate(words)?;
+            let miner_address =
+                seed::transparent_address(&mnemonic, passphrase.as_deref().unwrap_or_default(), 0, 0)?;
+
+            result!("{}", "Mnemonic:".bold());
+            result!("  {}", mnemonic);
+            say!();
+            result!("{} {}", "Mining address:".bold(), miner_address);
+            say!();
+            say!("Use it by saving the mnemonic to a file and adding to zeckit.toml:");
+            say!("  [faucet]");
+            say!("  mnemonic_file = \"faucet.mnemonic\"");
+            if passphrase.is_some() {
+                say!("  passphrase = \"...\"");
+            }
+            say!("or export ZECKIT_FAUCET_MNEMONIC, then run: zeckit up --fresh");
+
+            output::emit(&GeneratedSeed {
+                mnemonic,
+                miner_address,
+            })
+        }
+    }
+}
@@ -0,0 +1,375 @@
+use crate::config::{Backend, Settings};
+use crate::docker::compose::{DockerCompose, VOLUMES};
+use crate::docker::volume;
+use crate::error::{Result, ZecKitError};
+use crate::output;
+use crate::rpc::ZebraRpc;
+use clap::Subcommand;
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Where named snapshots live, relative to the project root
+const SNAPSHOT_DIR: &str = ".zeckit/snapshots";
+const MANIFEST_FILE: &str = "manifest.json";
+/// Where a snapshot archive keeps the devnet's fixtures
+const FIXTURES_DIR: &str = "fixtures";
+
+#[derive(Subcommand)]
+pub enum SnapshotAction {
+    /// Stop the devnet, archive its volumes and fixtures, then restart it
+    Save {
+        /// Snapshot name, or a path ending in .tar.gz
+        name: String,
+    },
+    /// Replace the devnet volumes and fixtures with a saved snapshot
+    Restore {
+        /// Snapshot name, or a path ending in .tar.gz
+        name: String,
+    },
+    /// List saved snapshots
+    List,
+}
+
+/// Stored as `manifest.json` at the root of every snapshot tarball
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    name: String,
+    created_at: u64,
+    backend: Backend,
+    block_height: Option<u64>,
+    /// Compose volume keys, each archived as `volumes/<key>.tar`
+    volumes: Vec<String>,
+    /// Files copied from the devnet's fixtures directory
+    fixtures: Vec<String>,
+    /// Services that were running when the snapshot was taken; restarted on restore
+    services: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct SnapshotReport {
+    path: PathBuf,
+    #[serde(flatten)]
+    manifest: Manifest,
+}
+
+#[derive(Serialize)]
+struct SnapshotEntry {
+    name: String,
+    path: PathBuf,
+    size_bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    manifest: Option<Manifest>,
+}
+
+pub async fn execute(settings: &Settings, action: SnapshotAction) -> Result<()> {
+    match action {
+        SnapshotAction::Save { name } => save(settings, &name).await,
+        SnapshotAction::Restore { name } => restore(settings, &name).await,
+        SnapshotAction::List => list(),
+    }
+}
+
+async fn save(settings: &Settings, name: &str) -> Result<()> {
+    let path = snapshot_path(name)?;
+    let compose = DockerCompose::new(settings)?;
+    let project = compose.project_name()?;
+
+    let volumes: Vec<String> = VOLUMES
+        .iter()
+        .filter(|key| volume::exists(&volume::compose_name(&project, key)))
+        .map(|key| key.to_string())
+        .collect();
+
+    if !volumes.iter().any(|v| v == "zebra-data") {
+        return Err(ZecKitError::Snapshot(
+            "No zebra-data volume found (start the devnet with: zeckit up)".into(),
+        ));
+    }
+
+    // Read the height before stopping; a snapshot of a stopped devnet just has none
+    let block_height = ZebraRpc::new(settings).get_block_count().await.ok();
+    let services: Vec<String> = compose
+        .ps()?
+        .into_iter()
+        .filter(|c| c.is_running())
+        .map(|c| c.service)
+        .collect();
+
+    say!("{} Stopping services for a consistent snapshot...", "🛑".yellow());
+    compose.stop()?;
+
+    let running = services.clone();
+    let manifest = Manifest {
+        name: name.to_string(),
+        created_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        backend: settings.backend,
+        block_height,
+        volumes,
+        fixtures: Vec::new(),
+        services,
+    };
+
+    let fixtures = crate::utils::project_dir()?.join(settings.fixtures_dir());
+    let staging = staging_dir(name)?;
+    let result = write_snapshot(&path, &project, &fixtures, &staging, manifest);
+    let _ = fs::remove_dir_all(&staging);
+
+    // Bring the devnet back to where it was, even if archiving failed
+    if !running.is_empty() {
+        say!("{} Restarting services...", "🚀".green());
+        let names: Vec<&str> = running.iter().map(String::as_str).collect();
+        compose.up(&names)?;
+    }
+
+    let manifest = result?;
+    let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+
+    say!();
+    result!("{} Snapshot '{}' saved ({})", "✓".green(), name, crate::utils::format_bytes(size));
+    result!("  Path:    {}", path.display());
+    if let Some(height) = manifest.block_height {
+        result!("  Height:  {}", height);
+    }
+    result!("  Volumes: {}", manifest.volumes.join(", "));
+    say!();
+
+    output::emit(&SnapshotReport { path, manifest })
+}
+
+fn write_snapshot(
+    path: &Path,
+    project: &str,
+    fixtures: &Path,
+    staging: &Path,
+    mut manifest: Manifest,
+) -> Result<Manifest> {
+    let volume_dir = staging.join("volumes");
+    fs::create_dir_all(&volume_dir)?;
+
+    for key in &manifest.volumes {
+        say!("  Archiving {}...", key);
+        volume::export(&volume::compose_name(project, key), &volume_dir, &format!("{}.tar", key))?;
+    }
+
+    manifest.fixtures = copy_fixtures(fixtures, &staging.join(FIXTURES_DIR))?;
+    fs::write(staging.join(MANIFEST_FILE), serde_json::to_string_pretty(&manifest)?)?;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    tar(&[
+        "czf".as_ref(),
+        path.as_os_str(),
+        "-C".as_ref(),
+        staging.as_os_str(),
+        ".".as_ref(),
+    ])?;
+
+    Ok(manifest)
+}
+
+async fn restore(settings: &Settings, name: &str) -> Result<()> {
+    let path = snapshot_path(name)?;
+    if !path.exists() {
+        return Err(ZecKitError::Snapshot(format!(
+            "No snapshot at {} (see: zeckit snapshot list)",
+            path.display()
+        )));
+    }
+
+    let staging = staging_dir(name)?;
+    let result = restore_from(settings, &path, &staging);
+    let _ = fs::remove_dir_all(&staging);
+    let manifest = result?;
+
+    if !manifest.services.is_empty() {
+        say!("{} Starting services...", "🚀".green());
+        let compose = DockerCompose::new(settings)?;
+        let names: Vec<&str> = manifest.services.iter().map(String::as_str).collect();
+        compose.up(&names)?;
+    }
+
+    say!();
+    result!("{} Snapshot '{}' restored", "✓".green(), manifest.name);
+    if let Some(height) = manifest.block_height {
+        result!("  Height:  {}", height);
+    }
+    result!("  Backend: {}", manifest.backend);
+    if manifest.services.is_empty() {
+        say!("  Services were stopped when the snapshot was taken; start them with: zeckit up");
+    }
+    say!();
+
+    output::emit(&SnapshotReport { path, manifest })
+}
+
+fn restore_from(settings: &Settings, path: &Path, staging: &Path) -> Result<Manifest> {
+    fs::create_dir_all(staging)?;
+    tar(&[
+        "xzf".as_ref(),
+        path.as_os_str(),
+        "-C".as_ref(),
+        staging.as_os_str(),
+    ])?;
+
+    let manifest: Manifest = serde_json::from_str(
+        &fs::read_to_string(staging.join(MANIFEST_FILE))
+            .map_err(|_| ZecKitError::Snapshot(format!("{} has no manifest", path.display())))?,
+    )?;
+
+    if manifest.backend != settings.backend {
+        say!(
+            "{} Snapshot was taken with backend '{}' (current: '{}')",
+            "⚠".yellow(),
+            manifest.backend,
+            settings.backend
+        );
+    }
+
+    let compose = DockerCompose::new(settings)?;
+    let project = compose.project_name()?;
+
+    say!("{} Stopping services...", "🛑".yellow());
+    compose.stop()?;
+
+    let volume_dir = staging.join("volumes");
+    for key in &manifest.volumes {
+        say!("  Restoring {}...", key);
+        let name = volume::compose_name(&project, key);
+        if !volume::exists(&name) {
+            volume::create_for_compose(&project, key)?;
+        }
+        volume::import(&name, &volume_dir, &format!("{}.tar", key))?;
+    }
+
+    copy_fixtures(
+        &staging.join(FIXTURES_DIR),
+        &crate::utils::project_dir()?.join(settings.fixtures_dir()),
+    )?;
+
+    Ok(manifest)
+}
+
+fn list() -> Result<()> {
+    let dir = crate::utils::project_dir()?.join(SNAPSHOT_DIR);
+    let mut entries = Vec::new();
+
+    if dir.is_dir() {
+        let mut paths: Vec<PathBuf> = fs::read_dir(&dir)?
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.to_string_lossy().ends_with(".tar.gz"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().trim_end_matches(".tar.gz").to_string())
+                .unwrap_or_default();
+            let size_bytes = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            let manifest = read_manifest(&path).ok();
+
+            let detail = match &manifest {
+                Some(m) => format!(
+                    "height {}, backend {}",
+                    m.block_height.map(|h| h.to_string()).unwrap_or_else(|| "?".into()),
+                    m.backend
+                ),
+                None => "no manifest".to_string(),
+            };
+            result!(
+                "  {:<20} {:>10}  {}",
+                name.bold(),
+                crate::utils::format_bytes(size_bytes),
+                detail
+            );
+
+            entries.push(SnapshotEntry { name, path, size_bytes, manifest });
+        }
+    }
+
+    if entries.is_empty() {
+        result!("No snapshots in {}", dir.display());
+    }
+
+    output::emit(&entries)
+}
+
+/// Plain names live in the project snapshot directory; anything that looks like a path is used as-is
+fn snapshot_path(name: &str) -> Result<PathBuf> {
+    if name.ends_with(".tar.gz") || name.contains('/') || name.contains(std::path::MAIN_SEPARATOR) {
+        return Ok(PathBuf::from(name));
+    }
+    if name.is_empty() || name.starts_with('.') {
+        return Err(ZecKitError::Snapshot(format!("Invalid snapshot name '{}'", name)));
+    }
+
+    Ok(crate::utils::project_dir()?
+        .join(SNAPSHOT_DIR)
+        .join(format!("{}.tar.gz", name)))
+}
+
+/// Scratch directory for assembling or unpacking a snapshot, emptied first
+fn staging_dir(name: &str) -> Result<PathBuf> {
+    let label: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    let dir = crate::utils::project_dir()?
+        .join(SNAPSHOT_DIR)
+        .join(format!(".staging-{}", label));
+
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Copy the top-level files of `from` into `to`, returning their names
+fn copy_fixtures(from: &Path, to: &Path) -> Result<Vec<String>> {
+    let mut copied = Vec::new();
+    if !from.is_dir() {
+        return Ok(copied);
+    }
+
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let path = entry?.path();
+        if let (true, Some(file_name)) = (path.is_file(), path.file_name()) {
+            fs::copy(&path, to.join(file_name))?;
+            copied.push(file_name.to_string_lossy().to_string());
+        }
+    }
+    copied.sort();
+
+    Ok(copied)
+}
+
+fn read_manifest(path: &Path) -> Result<Manifest> {
+    let stdout = tar(&[
+        "xzf".as_ref(),
+        path.as_os_str(),
+        "-O".as_ref(),
+        format!("./{}", MANIFEST_FILE).as_ref(),
+    ])?;
+    Ok(serde_json::from_slice(&stdout)?)
+}
+
+fn tar(args: &[&std::ffi::OsStr]) -> Result<Vec<u8>> {
+    let output = Command::new("tar").args(args).output()?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(ZecKitError::Snapshot(format!("tar failed: {}", error.trim())));
+    }
+
+    Ok(output.stdout)
+}
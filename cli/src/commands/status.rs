@@ -1,65 +1,215 @@
-use crate::docker::compose::DockerCompose;
+use crate::config::{Backend, Settings};
+use crate::docker::compose::{ContainerState, DockerCompose};
 use crate::error::Result;
+use crate::output;
+use crate::rpc::faucet::FaucetStats;
+use crate::rpc::indexer::LightdInfo;
+use crate::rpc::zebra::{BlockchainInfo, NetworkUpgrade};
+use crate::rpc::{FaucetApi, IndexerClient, ZebraRpc};
 use colored::*;
-use reqwest::Client;
-use serde_json::Value;
-
-pub async fn execute() -> Result<()> {
-    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
-    println!("{}", "  ZecKit - Devnet Status".cyan().bold());
-    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
-    println!();
-    
-    let compose = DockerCompose::new()?;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct StatusReport {
+    services: Vec<ContainerState>,
+    zebra: ZebraStatus,
+    /// Absent when no indexer container exists (node-only devnet)
+    indexer: Option<IndexerStatus>,
+    /// Absent when no faucet container exists (node-only devnet)
+    faucet: Option<FaucetStatus>,
+}
+
+#[derive(Serialize)]
+struct ZebraStatus {
+    url: String,
+    reachable: bool,
+    height: Option<u64>,
+    chain: Option<String>,
+    best_block_hash: Option<String>,
+    upgrades: Vec<NetworkUpgrade>,
+}
+
+#[derive(Serialize)]
+struct IndexerStatus {
+    service: String,
+    url: String,
+    reachable: bool,
+    height: Option<u64>,
+    /// Blocks behind Zebra's tip
+    lag: Option<u64>,
+    /// Vendor, version and chain from `GetLightdInfo`
+    info: Option<LightdInfo>,
+}
+
+#[derive(Serialize)]
+struct FaucetStatus {
+    url: String,
+    reachable: bool,
+    synced_height: Option<u64>,
+    /// Blocks the wallet is behind Zebra's tip
+    lag: Option<u64>,
+    #[serde(flatten)]
+    stats: Option<FaucetStats>,
+}
+
+pub async fn execute(settings: &Settings) -> Result<()> {
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!("{}", "  ZecKit - Devnet Status".cyan().bold());
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!();
+
+    let compose = DockerCompose::new(settings)?;
     let containers = compose.ps()?;
-    
+
     // Display container status
-    for container in containers {
-        let status_color = if container.contains("Up") {
-            "green"
+    if containers.is_empty() {
+        result!("  {}", "No containers (run `zeckit up`)".yellow());
+    } else {
+        result!("  {}", format!("{:<22} {:<10} {:<10} {}", "CONTAINER", "STATE", "HEALTH", "STATUS").bold());
+    }
+    for container in &containers {
+        let health = if container.health.is_empty() { "-" } else { container.health.as_str() };
+        let line = format!("{:<22} {:<10} {:<10} {}", container.name, container.state, health, container.status);
+        if container.is_running() && container.health != "unhealthy" {
+            result!("  {}", line.green());
+        } else if container.is_running() {
+            result!("  {}", line.yellow());
         } else {
-            "red"
-        };
-        
-        println!("  {}", container.color(status_color));
+            result!("  {}", line.red());
+        }
+    }
+
+    say!();
+
+    // Zebra: tip and network upgrades
+    let info = ZebraRpc::new(settings).get_blockchain_info().await.ok();
+    let tip = info.as_ref().map(|info| info.blocks);
+    match &info {
+        Some(info) => {
+            result!("  {} {} - Height: {} ({})", "✓".green(), "Zebra".bold(), info.blocks, info.chain);
+            result!("      Tip: {}", info.best_block_hash);
+            result!("      Upgrades: {}", describe_upgrades(info));
+        }
+        None => result!("  {} {} - Not responding", "✗".red(), "Zebra".bold()),
     }
-    
-    println!();
-    
-    // Check service health
-    let client = Client::new();
-    
-    // Zebra
-    print_service_status(&client, "Zebra", "http://127.0.0.1:8232").await;
-    
-    // Faucet
-    print_service_status(&client, "Faucet", "http://127.0.0.1:8080/stats").await;
-    
-    println!();
-    Ok(())
-}
 
-async fn print_service_status(client: &Client, name: &str, url: &str) {
-    match client.get(url).send().await {
-        Ok(resp) if resp.status().is_success() => {
-            if let Ok(json) = resp.json::<Value>().await {
-                println!("  {} {} - {}", "✓".green(), name.bold(), format_json(&json));
-            } else {
-                println!("  {} {} - {}", "✓".green(), name.bold(), "OK");
+    // Indexer: latest block over gRPC
+    let indexer = match running_indexer(&containers) {
+        Some(backend) => {
+            let client = IndexerClient::new(settings);
+            let info = client.lightd_info().await.ok();
+            let height = client.latest_block().await.ok().map(|block| block.height);
+            let lag = tip.zip(height).map(|(tip, height)| tip.saturating_sub(height));
+            let name = backend.display_name();
+            match (height, lag) {
+                (Some(height), Some(lag)) => {
+                    result!("  {} {} - Height: {} ({})", "✓".green(), name.bold(), height, describe_lag(lag))
+                }
+                (Some(height), None) => result!("  {} {} - Height: {}", "✓".green(), name.bold(), height),
+                (None, _) => result!("  {} {} - Not responding", "✗".red(), name.bold()),
+            }
+            if let Some(info) = &info {
+                result!("      {} {} (chain {})", info.vendor, info.version, info.chain_name);
             }
+            Some(IndexerStatus {
+                service: backend.service().unwrap_or_default().to_string(),
+                url: settings.backend_url(),
+                reachable: height.is_some(),
+                height,
+                lag,
+                info,
+            })
         }
-        _ => {
-            println!("  {} {} - {}", "✗".red(), name.bold(), "Not responding");
+        None => {
+            result!("  {} {} - Not running (node-only devnet)", "-".dimmed(), "Indexer".bold());
+            None
         }
-    }
+    };
+
+    // Faucet: wallet sync height and pool balances
+    let faucet = if has_faucet(&containers) {
+        let stats = FaucetApi::new(settings).stats().await.ok();
+        let synced_height = stats.as_ref().and_then(|s| s.synced_height);
+        let lag = tip.zip(synced_height).map(|(tip, synced)| tip.saturating_sub(synced));
+        match &stats {
+            Some(stats) => {
+                match (synced_height, lag) {
+                    (Some(synced), Some(lag)) => {
+                        result!("  {} {} - Synced to: {} ({})", "✓".green(), "Faucet".bold(), synced, describe_lag(lag))
+                    }
+                    (Some(synced), None) => result!("  {} {} - Synced to: {}", "✓".green(), "Faucet".bold(), synced),
+                    (None, _) => result!("  {} {} - Not synced yet", "✓".green(), "Faucet".bold()),
+                }
+                result!(
+                    "      Balance: {} ZEC (transparent {}, sapling {}, orchard {})",
+                    stats.current_balance,
+                    stats.transparent_balance,
+                    stats.sapling_balance,
+                    stats.orchard_balance
+                );
+            }
+            None => result!("  {} {} - Not responding", "✗".red(), "Faucet".bold()),
+        }
+        Some(FaucetStatus {
+            url: settings.faucet_api_url(),
+            reachable: stats.is_some(),
+            synced_height,
+            lag,
+            stats,
+        })
+    } else {
+        result!("  {} {} - Not running (node-only devnet)", "-".dimmed(), "Faucet".bold());
+        None
+    };
+
+    say!();
+
+    output::emit(&StatusReport {
+        services: containers,
+        zebra: ZebraStatus {
+            url: settings.zebra_rpc_url(),
+            reachable: info.is_some(),
+            height: tip,
+            chain: info.as_ref().map(|info| info.chain.clone()),
+            best_block_hash: info.as_ref().map(|info| info.best_block_hash.clone()),
+            upgrades: info.map(|info| info.upgrades.into_values().collect()).unwrap_or_default(),
+        },
+        indexer,
+        faucet,
+    })
+}
+
+/// Indexer backend whose container exists in this devnet. The unnamed default
+/// devnet doesn't record its backend, so it is read off the containers.
+fn running_indexer(containers: &[ContainerState]) -> Option<Backend> {
+    [Backend::Lwd, Backend::Zaino]
+        .into_iter()
+        .find(|backend| containers.iter().any(|c| backend.service() == Some(c.service.as_str())))
+}
+
+/// Whether a faucet container exists in this devnet, read off the containers
+/// for the same reason as [`running_indexer`].
+fn has_faucet(containers: &[ContainerState]) -> bool {
+    [Backend::Lwd, Backend::Zaino]
+        .into_iter()
+        .any(|backend| containers.iter().any(|c| backend.faucet_service() == Some(c.service.as_str())))
+}
+
+/// `NU5@1 active, NU6@150 pending` in activation order
+fn describe_upgrades(info: &BlockchainInfo) -> String {
+    let mut upgrades: Vec<&NetworkUpgrade> = info.upgrades.values().collect();
+    upgrades.sort_by_key(|u| u.activation_height);
+    upgrades
+        .iter()
+        .map(|u| format!("{}@{} {}", u.name, u.activation_height, u.status))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
-fn format_json(json: &Value) -> String {
-    if let Some(height) = json.get("zebra_height") {
-        format!("Height: {}", height)
-    } else if let Some(balance) = json.get("current_balance") {
-        format!("Balance: {} ZEC", balance)
+fn describe_lag(lag: u64) -> String {
+    if lag == 0 {
+        "at tip".green().to_string()
     } else {
-        "Running".to_string()
+        format!("{} behind", lag).yellow().to_string()
     }
-}
\ No newline at end of file
+}
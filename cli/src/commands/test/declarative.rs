@@ -0,0 +1,294 @@
+use super::registry::{TestCase, TestKind};
+use super::{capture, TestContext};
+use crate::error::{Result, ZecKitError};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Project directory searched for test files when `--tests-dir` is not given
+pub const DEFAULT_DIR: &str = "zeckit-tests";
+
+/// One `*.toml` file holding any number of `[[test]]` tables
+#[derive(Debug, Deserialize)]
+struct TestFile {
+    #[serde(default, rename = "test")]
+    tests: Vec<DeclaredTest>,
+}
+
+/// A regression check described in TOML rather than code
+#[derive(Debug, Deserialize)]
+pub struct DeclaredTest {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(flatten)]
+    pub request: Request,
+    /// Dotted paths into the JSON response and the values they must equal
+    #[serde(default)]
+    pub expect: BTreeMap<String, Value>,
+    /// Dotted paths that must be present in the JSON response
+    #[serde(default)]
+    pub exists: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum Request {
+    /// HTTP request against the faucet API
+    Http {
+        #[serde(default = "default_http_method")]
+        method: String,
+        path: String,
+        body: Option<Value>,
+        #[serde(default = "default_status")]
+        status: u16,
+    },
+    /// JSON-RPC call against Zebra; assertions apply to `result`
+    Rpc {
+        method: String,
+        #[serde(default)]
+        params: Vec<Value>,
+        /// Expect the call to fail with this RPC error code
+        error: Option<i64>,
+    },
+}
+
+fn default_http_method() -> String {
+    "GET".to_string()
+}
+
+fn default_status() -> u16 {
+    200
+}
+
+/// Load every `*.toml` file in `dir`, in file name order
+pub fn load_dir(dir: &Path) -> Result<Vec<TestCase>> {
+    let mut paths = fs::read_dir(dir)
+        .map_err(|e| ZecKitError::Config(format!("Could not read tests directory {:?}: {}", dir, e)))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut cases = Vec::new();
+    for path in paths {
+        let contents = fs::read_to_string(&path)?;
+        let file = parse(&contents)
+            .map_err(|e| ZecKitError::Config(format!("Invalid test file {:?}: {}", path, e)))?;
+        cases.extend(file.tests.into_iter().map(into_case));
+    }
+
+    Ok(cases)
+}
+
+fn parse(contents: &str) -> std::result::Result<TestFile, toml::de::Error> {
+    toml::from_str(contents)
+}
+
+fn into_case(test: DeclaredTest) -> TestCase {
+    let kind = match test.request {
+        Request::Http { .. } => "http",
+        Request::Rpc { .. } => "rpc",
+    };
+
+    let mut tags = test.tags.clone();
+    tags.extend(["custom".to_string(), kind.to_string()]);
+
+    TestCase {
+        name: test.name.clone(),
+        description: test.description.clone().unwrap_or_else(|| test.name.clone()),
+        tags,
+        kind: TestKind::Declarative(Box::new(test)),
+    }
+}
+
+pub async fn run(ctx: &TestContext, test: &DeclaredTest, log: &mut Vec<String>) -> Result<()> {
+    let body = match &test.request {
+        Request::Http { method, path, body, status } => {
+            let method = reqwest::Method::from_bytes(method.to_uppercase().as_bytes())
+                .map_err(|_| ZecKitError::Config(format!("Invalid HTTP method: {}", method)))?;
+            let request = format!("{} {}", method, path);
+
+            let mut req = ctx.client.request(method, format!("{}{}", ctx.faucet_url, path));
+            if let Some(body) = body {
+                req = req.json(body);
+            }
+
+            let resp = req.send().await?;
+            let code = resp.status().as_u16();
+            let text = resp.text().await?;
+            log.push(format!("{} -> {} {}", request, code, text));
+
+            if code != *status {
+                return Err(ZecKitError::HealthCheck(format!(
+                    "{} returned HTTP {} (expected {})",
+                    request, code, status
+                )));
+            }
+
+            if test.expect.is_empty() && test.exists.is_empty() {
+                return Ok(());
+            }
+            serde_json::from_str(&text)?
+        }
+        Request::Rpc { method, params, error } => {
+            match (ctx.zebra.call(method, Value::Array(params.clone())).await, error) {
+                (Ok(result), None) => {
+                    capture(log, method, &result);
+                    result
+                }
+                (Ok(result), Some(code)) => {
+                    capture(log, method, &result);
+                    return Err(ZecKitError::HealthCheck(format!(
+                        "{} succeeded but RPC error {} was expected",
+                        method, code
+                    )));
+                }
+                (Err(ZecKitError::Rpc { code, message }), Some(expected)) => {
+                    log.push(format!("{} -> RPC error {}: {}", method, code, message));
+                    if code != *expected {
+                        return Err(ZecKitError::HealthCheck(format!(
+                            "{} failed with RPC error {} (expected {})",
+                            method, code, expected
+                        )));
+                    }
+                    return Ok(());
+                }
+                (Err(e), _) => return Err(e),
+            }
+        }
+    };
+
+    check(&body, &test.expect, &test.exists)
+}
+
+/// Compare the response against the test's `expect` and `exists` assertions
+fn check(body: &Value, expect: &BTreeMap<String, Value>, exists: &[String]) -> Result<()> {
+    for (path, expected) in expect {
+        let actual = lookup(body, path)
+            .ok_or_else(|| ZecKitError::HealthCheck(format!("Missing field '{}'", path)))?;
+        if !values_equal(actual, expected) {
+            return Err(ZecKitError::HealthCheck(format!(
+                "'{}' is {} (expected {})",
+                path, actual, expected
+            )));
+        }
+    }
+
+    for path in exists {
+        if lookup(body, path).is_none() {
+            return Err(ZecKitError::HealthCheck(format!("Missing field '{}'", path)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Follow a dotted path such as `upgrades.0.name`; `.` alone is the whole value
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    if path == "." {
+        return Some(value);
+    }
+
+    path.split('.').try_fold(value, |current, segment| match current {
+        Value::Object(map) => map.get(segment),
+        Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+        _ => None,
+    })
+}
+
+/// TOML integers and JSON floats describe the same amounts, so numbers compare by value
+fn values_equal(actual: &Value, expected: &Value) -> bool {
+    match (actual.as_f64(), expected.as_f64()) {
+        (Some(a), Some(b)) => a == b,
+        _ => actual == expected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(case: &TestCase) -> &Request {
+        match &case.kind {
+            TestKind::Declarative(test) => &test.request,
+            TestKind::Builtin(_) => panic!("expected a declarative test"),
+        }
+    }
+
+    #[test]
+    fn parses_http_and_rpc_tests() {
+        let file = parse(
+            r#"
+            [[test]]
+            name = "faucet_stats"
+            tags = ["faucet"]
+            kind = "http"
+            path = "/stats"
+            exists = ["faucet_address"]
+
+            [[test]]
+            name = "chain_is_regtest"
+            kind = "rpc"
+            method = "getblockchaininfo"
+            expect = { chain = "test" }
+
+            [[test]]
+            name = "unknown_block"
+            kind = "rpc"
+            method = "getblock"
+            params = ["999999", 1]
+            error = -8
+            "#,
+        )
+        .unwrap();
+
+        let cases: Vec<_> = file.tests.into_iter().map(into_case).collect();
+        assert_eq!(cases.len(), 3);
+        assert_eq!(cases[0].tags, ["faucet", "custom", "http"]);
+
+        match request(&cases[0]) {
+            Request::Http { method, status, .. } => {
+                assert_eq!(method, "GET");
+                assert_eq!(*status, 200);
+            }
+            other => panic!("expected an HTTP test, got {:?}", other),
+        }
+        match request(&cases[2]) {
+            Request::Rpc { params, error, .. } => {
+                assert_eq!(params, &vec![json!("999999"), json!(1)]);
+                assert_eq!(*error, Some(-8));
+            }
+            other => panic!("expected an RPC test, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_kind() {
+        assert!(parse("[[test]]\nname = \"x\"\nkind = \"grpc\"\n").is_err());
+    }
+
+    #[test]
+    fn checks_dotted_paths() {
+        let body = json!({
+            "chain": "test",
+            "blocks": 120,
+            "upgrades": [{ "name": "NU5", "height": 1.0 }]
+        });
+
+        let expect = BTreeMap::from([
+            ("chain".to_string(), json!("test")),
+            ("upgrades.0.height".to_string(), json!(1)),
+        ]);
+        assert!(check(&body, &expect, &["blocks".to_string()]).is_ok());
+
+        let wrong = BTreeMap::from([("blocks".to_string(), json!(121))]);
+        assert!(check(&body, &wrong, &[]).is_err());
+        assert!(check(&body, &BTreeMap::new(), &["upgrades.1".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,553 @@
+pub mod declarative;
+pub mod registry;
+pub mod report;
+
+use crate::config::Settings;
+use crate::error::{Result, ZecKitError};
+use crate::output;
+use crate::rpc::{FaucetApi, ZebraRpc};
+use crate::wait;
+use colored::*;
+use registry::{Builtin, TestCase, TestKind};
+use reqwest::Client;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::PathBuf;
+use std::time::Instant;
+use tokio::time::Duration;
+
+pub use report::ReportSpec;
+
+/// Which tests to run and how, from the `zeckit test` flags
+#[derive(Debug, Default)]
+pub struct TestOptions {
+    /// Only run tests whose name contains, or whose tags include, one of these
+    pub filters: Vec<String>,
+    /// Skip tests whose name contains, or whose tags include, one of these
+    pub skips: Vec<String>,
+    /// Extra attempts for a failing test
+    pub retries: u32,
+    /// Per-attempt deadline
+    pub timeout: Option<Duration>,
+    /// Directory of declarative `*.toml` test files
+    pub tests_dir: Option<PathBuf>,
+    pub reports: Vec<ReportSpec>,
+}
+
+#[derive(Serialize)]
+struct TestReport {
+    passed: usize,
+    failed: usize,
+    results: Vec<TestResult>,
+}
+
+/// Outcome of a single test, as shown in `--output json` and written to `--report` files
+#[derive(Serialize)]
+pub struct TestResult {
+    pub name: String,
+    pub passed: bool,
+    pub attempts: u32,
+    pub duration_ms: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Faucet / RPC responses seen while the test ran
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub captured: Vec<String>,
+}
+
+/// Shared state handed to every test
+pub struct TestContext {
+    pub client: Client,
+    pub zebra: ZebraRpc,
+    pub faucet: FaucetApi,
+    pub faucet_url: String,
+    /// How long to wait for a wallet sync / a transaction to be mined
+    sync_timeout: Duration,
+    tx_timeout: Duration,
+    /// Progress prefix of the running test, re-printed after verbose output
+    label: String,
+}
+
+pub async fn execute(settings: &Settings, options: &TestOptions) -> Result<()> {
+    let tests_dir = match &options.tests_dir {
+        Some(dir) => Some(dir.clone()),
+        None => Some(crate::utils::project_dir()?.join(declarative::DEFAULT_DIR))
+            .filter(|dir| dir.is_dir()),
+    };
+
+    let mut cases = registry::builtin();
+    if let Some(dir) = &tests_dir {
+        cases.extend(declarative::load_dir(dir)?);
+    }
+
+    let cases = registry::select(cases, &options.filters, &options.skips);
+    if cases.is_empty() {
+        return Err(ZecKitError::Config(
+            "No tests match the given --filter / --skip".into()
+        ));
+    }
+
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!("{}", "  ZecKit - Running Smoke Tests".cyan().bold());
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!();
+
+    let mut ctx = TestContext {
+        client: Client::new(),
+        zebra: ZebraRpc::new(settings),
+        faucet: FaucetApi::new(settings),
+        faucet_url: settings.faucet_api_url(),
+        sync_timeout: Duration::from_secs(settings.timeouts.sync_secs),
+        tx_timeout: Duration::from_secs(settings.timeouts.tx_secs),
+        label: String::new(),
+    };
+    let mut results = Vec::new();
+
+    let total = cases.len();
+    for (i, case) in cases.iter().enumerate() {
+        ctx.label = format!("  [{}/{}] {}... ", i + 1, total, case.description);
+        results.push(run_case(&ctx, case, options).await);
+    }
+
+    let passed = results.iter().filter(|r| r.passed).count();
+    let failed = results.len() - passed;
+
+    say!();
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    result!("  Tests passed: {}", passed.to_string().green());
+    result!("  Tests failed: {}", failed.to_string().red());
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!();
+
+    for spec in &options.reports {
+        report::write(spec, &results)?;
+        result!("  Report written: {}", spec.path.display());
+    }
+
+    output::emit(&TestReport {
+        passed,
+        failed,
+        results,
+    })?;
+
+    if failed > 0 {
+        return Err(crate::error::ZecKitError::HealthCheck(
+            format!("{} test(s) failed", failed)
+        ));
+    }
+
+    Ok(())
+}
+
+/// Run one test with retries and timeout, printing PASS/FAIL as it goes
+async fn run_case(ctx: &TestContext, case: &TestCase, options: &TestOptions) -> TestResult {
+    let start = Instant::now();
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+        say_inline!("{}", ctx.label);
+
+        let mut log = Vec::new();
+        let outcome = match options.timeout {
+            Some(limit) => tokio::time::timeout(limit, run(ctx, case, &mut log))
+                .await
+                .unwrap_or_else(|_| Err(ZecKitError::HealthCheck(
+                    format!("timed out after {}s", limit.as_secs_f64())
+                ))),
+            None => run(ctx, case, &mut log).await,
+        };
+
+        match outcome {
+            Ok(()) => {
+                say!("{}", "PASS".green());
+                return TestResult {
+                    name: case.name.clone(),
+                    passed: true,
+                    attempts,
+                    duration_ms: start.elapsed().as_millis(),
+                    error: None,
+                    captured: log,
+                };
+            }
+            Err(e) if attempts <= options.retries => {
+                say!("{} {} (retry {}/{})", "FAIL".yellow(), e, attempts, options.retries);
+            }
+            Err(e) => {
+                say!("{} {}", "FAIL".red(), e);
+                return TestResult {
+                    name: case.name.clone(),
+                    passed: false,
+                    attempts,
+                    duration_ms: start.elapsed().as_millis(),
+                    error: Some(e.to_string()),
+                    captured: log,
+                };
+            }
+        }
+    }
+}
+
+async fn run(ctx: &TestContext, case: &TestCase, log: &mut Vec<String>) -> Result<()> {
+    match &case.kind {
+        TestKind::Builtin(Builtin::ZebraRpc) => test_zebra_rpc(ctx, log).await,
+        TestKind::Builtin(Builtin::FaucetHealth) => test_faucet_health(ctx, log).await,
+        TestKind::Builtin(Builtin::FaucetAddress) => test_faucet_address(ctx, log).await,
+        TestKind::Builtin(Builtin::WalletSync) => test_wallet_sync(ctx, log).await,
+        TestKind::Builtin(Builtin::WalletShield) => test_wallet_shield(ctx, log).await,
+        TestKind::Builtin(Builtin::ShieldedSend) => test_shielded_send(ctx, log).await,
+        TestKind::Declarative(test) => declarative::run(ctx, test, log).await,
+    }
+}
+
+/// Keep a response body so failures can be diagnosed from the report alone
+fn capture(log: &mut Vec<String>, request: &str, body: &Value) {
+    log.push(format!("{} -> {}", request, body));
+}
+
+async fn test_zebra_rpc(ctx: &TestContext, log: &mut Vec<String>) -> Result<()> {
+    let height = ctx.zebra.call("getblockcount", serde_json::json!([])).await?;
+    capture(log, "getblockcount", &height);
+
+    Ok(())
+}
+
+async fn test_faucet_health(ctx: &TestContext, log: &mut Vec<String>) -> Result<()> {
+    let (client, faucet_url) = (&ctx.client, ctx.faucet_url.as_str());
+    let resp = client
+        .get(format!("{}/health", faucet_url))
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(crate::error::ZecKitError::HealthCheck(
+            "Faucet health check failed".into()
+        ));
+    }
+
+    let json: Value = resp.json().await?;
+    capture(log, "GET /health", &json);
+    
+    // Verify key health fields
+    if json.get("status").and_then(|v| v.as_str()) != Some("healthy") {
+        return Err(crate::error::ZecKitError::HealthCheck(
+            "Faucet not reporting healthy status".into()
+        ));
+    }
+
+    Ok(())
+}
+
+async fn test_faucet_address(ctx: &TestContext, log: &mut Vec<String>) -> Result<()> {
+    let (client, faucet_url) = (&ctx.client, ctx.faucet_url.as_str());
+    let resp = client
+        .get(format!("{}/address", faucet_url))
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(crate::error::ZecKitError::HealthCheck(
+            "Could not get faucet address".into()
+        ));
+    }
+
+    let json: Value = resp.json().await?;
+    capture(log, "GET /address", &json);
+    
+    // Verify both address types are present
+    if json.get("unified_address").is_none() {
+        return Err(crate::error::ZecKitError::HealthCheck(
+            "Missing unified address in response".into()
+        ));
+    }
+    
+    if json.get("transparent_address").is_none() {
+        return Err(crate::error::ZecKitError::HealthCheck(
+            "Missing transparent address in response".into()
+        ));
+    }
+
+    Ok(())
+}
+async fn test_wallet_sync(ctx: &TestContext, log: &mut Vec<String>) -> Result<()> {
+    let (client, faucet_url) = (&ctx.client, ctx.faucet_url.as_str());
+    let resp = client
+        .post(format!("{}/sync", faucet_url))
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(crate::error::ZecKitError::HealthCheck(
+            "Wallet sync failed".into()
+        ));
+    }
+
+    let json: Value = resp.json().await?;
+    capture(log, "POST /sync", &json);
+    
+    if json.get("status").and_then(|v| v.as_str()) != Some("synced") {
+        return Err(crate::error::ZecKitError::HealthCheck(
+            "Wallet sync did not complete successfully".into()
+        ));
+    }
+
+    Ok(())
+}
+
+async fn test_wallet_shield(ctx: &TestContext, log: &mut Vec<String>) -> Result<()> {
+    let (client, faucet_url) = (&ctx.client, ctx.faucet_url.as_str());
+    say!();
+    
+    // Step 1: Get current wallet balance via API
+    say!("    Checking wallet balance via API...");
+    let balance = get_wallet_balance_via_api(client, faucet_url, log).await?;
+    
+    let transparent_before = balance.transparent;
+    let orchard_before = balance.orchard;
+    
+    say!("    Transparent: {} ZEC", transparent_before);
+    say!("    Orchard: {} ZEC", orchard_before);
+    
+    // Step 2: If we have transparent funds >= 0.001 ZEC (accounting for fee), shield them
+    let min_shield_amount = 0.0002; // Need at least fee + some amount
+    
+    if transparent_before >= min_shield_amount {
+        say!("    Shielding {} ZEC to Orchard via API...", transparent_before);
+        
+        // Call the shield endpoint
+        let shield_resp = client
+            .post(format!("{}/shield", faucet_url))
+            .send()
+            .await?;
+        
+        if !shield_resp.status().is_success() {
+            let error_text = shield_resp.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            log.push(format!("POST /shield -> {}", error_text));
+            return Err(crate::error::ZecKitError::HealthCheck(
+                format!("Shield API call failed: {}", error_text)
+            ));
+        }
+        
+        let shield_json: Value = shield_resp.json().await?;
+        capture(log, "POST /shield", &shield_json);
+        
+        // Check shield status
+        let status = shield_json.get("status").and_then(|v| v.as_str()).unwrap_or("unknown");
+        
+        match status {
+            "shielded" => {
+                if let Some(txid) = shield_json.get("txid").and_then(|v| v.as_str()) {
+                    say!("    Shield transaction broadcast!");
+                    say!("    TXID: {}...", &txid[..16.min(txid.len())]);
+                    
+                    say!("    Waiting for transaction to confirm...");
+                    let height = wait::transaction_mined(&ctx.zebra, txid, ctx.tx_timeout).await?;
+                    say!("    Mined at height {}", height);
+                }
+                
+                // Sync wallet to see new balance
+                say!("    Syncing wallet to update balance...");
+                wait::wallet_synced(&ctx.faucet, &ctx.zebra, ctx.sync_timeout).await?;
+                
+                // Check balance after shielding
+                let balance_after = get_wallet_balance_via_api(client, faucet_url, log).await?;
+                
+                say!("    Balance after shield:");
+                say!("    Transparent: {} ZEC (was {})", balance_after.transparent, transparent_before);
+                say!("    Orchard: {} ZEC (was {})", balance_after.orchard, orchard_before);
+                
+                // Verify shield worked (balance changed)
+                if balance_after.orchard > orchard_before || balance_after.transparent < transparent_before {
+                    say!("    Shield successful - funds moved!");
+                } else {
+                    say!("    Shield transaction sent but balance not yet updated");
+                    say!("    (May need more time to confirm)");
+                }
+                
+                say!();
+                say_inline!("{}", ctx.label);
+                Ok(())
+            }
+            "no_funds" => {
+                say!("    No transparent funds to shield (already shielded)");
+                say!();
+                say_inline!("{}", ctx.label);
+                Ok(())
+            }
+            _ => {
+                say!("    Shield status: {}", status);
+                if let Some(msg) = shield_json.get("message").and_then(|v| v.as_str()) {
+                    say!("    Message: {}", msg);
+                }
+                say!();
+                say_inline!("{}", ctx.label);
+                Ok(())
+            }
+        }
+        
+    } else if orchard_before >= 0.001 {
+        say!("    Wallet already has {} ZEC shielded in Orchard - PASS", orchard_before);
+        say!();
+        say_inline!("{}", ctx.label);
+        Ok(())
+        
+    } else if transparent_before > 0.0 {
+        say!("    Wallet has {} ZEC transparent (too small to shield)", transparent_before);
+        say!("    Need at least {} ZEC to cover shield + fee", min_shield_amount);
+        say!("    SKIP (insufficient balance)");
+        say!();
+        say_inline!("{}", ctx.label);
+        Ok(())
+        
+    } else {
+        say!("    No balance found");
+        say!("    SKIP (needs mining to complete)");
+        say!();
+        say_inline!("{}", ctx.label);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct WalletBalance {
+    transparent: f64,
+    orchard: f64,
+}
+
+/// Get wallet balance using the /stats endpoint
+async fn get_wallet_balance_via_api(client: &Client, faucet_url: &str, log: &mut Vec<String>) -> Result<WalletBalance> {
+    let resp = client
+        .get(format!("{}/stats", faucet_url))
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        return Err(crate::error::ZecKitError::HealthCheck(
+            "Failed to get balance from stats endpoint".into()
+        ));
+    }
+
+    let json: Value = resp.json().await?;
+    capture(log, "GET /stats", &json);
+    
+    // Extract balance from stats endpoint
+    // Stats should have fields like: current_balance, transparent_balance, orchard_balance
+    let transparent = json.get("transparent_balance")
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.0);
+    
+    let orchard = json.get("orchard_balance")
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.0);
+    
+    Ok(WalletBalance {
+        transparent,
+        orchard,
+    })
+}
+
+/// Test 6: Shielded Send (E2E Golden Flow)
+/// This is the key test for Milestone 2 - sending shielded funds to another wallet
+async fn test_shielded_send(ctx: &TestContext, log: &mut Vec<String>) -> Result<()> {
+    let (client, faucet_url) = (&ctx.client, ctx.faucet_url.as_str());
+    say!();
+    
+    // Step 1: Check faucet has shielded funds
+    say!("    Checking faucet Orchard balance...");
+    let balance = get_wallet_balance_via_api(client, faucet_url, log).await?;
+    
+    if balance.orchard < 0.1 {
+        say!("    Faucet has insufficient Orchard balance: {} ZEC", balance.orchard);
+        say!("    SKIP (need at least 0.1 ZEC shielded)");
+        say!();
+        say_inline!("{}", ctx.label);
+        return Ok(());
+    }
+    
+    say!("    Faucet Orchard balance: {} ZEC", balance.orchard);
+    
+    // Sync to the tip so the shielded notes are spendable
+    say!("    Syncing wallet to ensure spendable balance...");
+    wait::wallet_synced(&ctx.faucet, &ctx.zebra, ctx.sync_timeout).await?;
+    
+    // Step 2: Get a test recipient address (using faucet's own UA for simplicity)
+    say!("    Getting recipient address...");
+    let addr_resp = client
+        .get(format!("{}/address", faucet_url))
+        .send()
+        .await?;
+    
+    if !addr_resp.status().is_success() {
+        return Err(crate::error::ZecKitError::HealthCheck(
+            "Failed to get recipient address".into()
+        ));
+    }
+    
+    let addr_json: Value = addr_resp.json().await?;
+    capture(log, "GET /address", &addr_json);
+    let recipient_address = addr_json.get("unified_address")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| crate::error::ZecKitError::HealthCheck(
+            "No unified address in response".into()
+        ))?;
+    
+    say!("    Recipient: {}...", &recipient_address[..20.min(recipient_address.len())]);
+    
+    // Step 3: Perform shielded send
+    let send_amount = 0.05; // Send 0.05 ZEC
+    say!("    Sending {} ZEC (shielded)...", send_amount);
+    
+    let send_resp = client
+        .post(format!("{}/send", faucet_url))
+        .json(&serde_json::json!({
+            "address": recipient_address,
+            "amount": send_amount,
+            "memo": "ZecKit smoke test - shielded send"
+        }))
+        .send()
+        .await?;
+    
+    if !send_resp.status().is_success() {
+        let error_text = send_resp.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+        log.push(format!("POST /send -> {}", error_text));
+        return Err(crate::error::ZecKitError::HealthCheck(
+            format!("Shielded send failed: {}", error_text)
+        ));
+    }
+    
+    let send_json: Value = send_resp.json().await?;
+    capture(log, "POST /send", &send_json);
+    
+    // Step 4: Verify transaction
+    let status = send_json.get("status").and_then(|v| v.as_str());
+    
+    if status == Some("sent") {
+        if let Some(txid) = send_json.get("txid").and_then(|v| v.as_str()) {
+            say!("    ✓ Shielded send successful!");
+            say!("    TXID: {}...", &txid[..16.min(txid.len())]);
+        }
+        
+        if let Some(new_balance) = send_json.get("orchard_balance").and_then(|v| v.as_f64()) {
+            say!("    New Orchard balance: {} ZEC (was {})", new_balance, balance.orchard);
+        }
+        
+        say!("    ✓ E2E Golden Flow Complete:");
+        say!("      - Faucet had shielded funds (Orchard)");
+        say!("      - Sent {} ZEC to recipient UA", send_amount);
+        say!("      - Transaction broadcast successfully");
+        
+        say!();
+        say_inline!("{}", ctx.label);
+        Ok(())
+    } else {
+        say!("    Unexpected status: {:?}", status);
+        if let Some(msg) = send_json.get("message").and_then(|v| v.as_str()) {
+            say!("    Message: {}", msg);
+        }
+        say!();
+        say_inline!("{}", ctx.label);
+        Err(crate::error::ZecKitError::HealthCheck(
+            "Shielded send did not complete as expected".into()
+        ))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+use super::declarative::DeclaredTest;
+
+/// A smoke test known to `zeckit test`
+pub struct TestCase {
+    /// Stable identifier used in reports and matched by `--filter` / `--skip`
+    pub name: String,
+    /// Human-readable label printed while the test runs
+    pub description: String,
+    pub tags: Vec<String>,
+    pub kind: TestKind,
+}
+
+pub enum TestKind {
+    Builtin(Builtin),
+    Declarative(Box<DeclaredTest>),
+}
+
+/// Tests compiled into the CLI
+#[derive(Debug, Clone, Copy)]
+pub enum Builtin {
+    ZebraRpc,
+    FaucetHealth,
+    FaucetAddress,
+    WalletSync,
+    WalletShield,
+    ShieldedSend,
+}
+
+impl TestCase {
+    fn builtin(name: &str, description: &str, tags: &[&str], test: Builtin) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            kind: TestKind::Builtin(test),
+        }
+    }
+
+    /// A term matches when it is part of the test name or equals one of its tags
+    pub fn matches(&self, term: &str) -> bool {
+        self.name.contains(term) || self.tags.iter().any(|tag| tag == term)
+    }
+}
+
+/// The built-in suite, in execution order
+pub fn builtin() -> Vec<TestCase> {
+    vec![
+        TestCase::builtin("zebra_rpc", "Zebra RPC connectivity", &["zebra", "rpc"], Builtin::ZebraRpc),
+        TestCase::builtin("faucet_health", "Faucet health check", &["faucet"], Builtin::FaucetHealth),
+        TestCase::builtin("faucet_address", "Faucet address retrieval", &["faucet"], Builtin::FaucetAddress),
+        TestCase::builtin("wallet_sync", "Wallet sync capability", &["faucet", "wallet"], Builtin::WalletSync),
+        TestCase::builtin("wallet_shield", "Wallet balance and shield", &["wallet", "shield"], Builtin::WalletShield),
+        TestCase::builtin("shielded_send", "Shielded send (E2E)", &["wallet", "shield", "e2e"], Builtin::ShieldedSend),
+    ]
+}
+
+/// Keep the tests matching any of `filters` (all tests if empty), minus those matching any of `skips`
+pub fn select(cases: Vec<TestCase>, filters: &[String], skips: &[String]) -> Vec<TestCase> {
+    cases
+        .into_iter()
+        .filter(|case| filters.is_empty() || filters.iter().any(|f| case.matches(f)))
+        .filter(|case| !skips.iter().any(|s| case.matches(s)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(cases: &[TestCase]) -> Vec<&str> {
+        cases.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn filter_and_skip_match_names_and_tags() {
+        let shield = select(builtin(), &["shield".into()], &[]);
+        assert_eq!(names(&shield), ["wallet_shield", "shielded_send"]);
+
+        let no_e2e = select(builtin(), &[], &["e2e".into()]);
+        assert_eq!(no_e2e.len(), 5);
+        assert!(!names(&no_e2e).contains(&"shielded_send"));
+
+        let faucet_only = select(builtin(), &["faucet".into()], &["wallet".into()]);
+        assert_eq!(names(&faucet_only), ["faucet_health", "faucet_address"]);
+    }
+}
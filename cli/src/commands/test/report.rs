@@ -0,0 +1,227 @@
+use super::TestResult;
+use crate::error::{Result, ZecKitError};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Machine-readable report formats understood by `zeckit test --report`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Junit,
+    Tap,
+}
+
+/// A `--report <format>=<path>` argument
+#[derive(Debug, Clone)]
+pub struct ReportSpec {
+    pub format: ReportFormat,
+    pub path: PathBuf,
+}
+
+impl FromStr for ReportSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (format, path) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected <format>=<path>, got '{}'", s))?;
+
+        let format = match format {
+            "junit" => ReportFormat::Junit,
+            "tap" => ReportFormat::Tap,
+            other => return Err(format!("unknown report format '{}' (use junit or tap)", other)),
+        };
+
+        if path.is_empty() {
+            return Err("report path must not be empty".into());
+        }
+
+        Ok(Self {
+            format,
+            path: PathBuf::from(path),
+        })
+    }
+}
+
+/// Render `results` in the requested format and write them to disk
+pub fn write(spec: &ReportSpec, results: &[TestResult]) -> Result<()> {
+    let contents = match spec.format {
+        ReportFormat::Junit => junit(results),
+        ReportFormat::Tap => tap(results),
+    };
+
+    if let Some(parent) = spec.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&spec.path, contents).map_err(|e| {
+        ZecKitError::Config(format!("Could not write report {:?}: {}", spec.path, e))
+    })
+}
+
+fn junit(results: &[TestResult]) -> String {
+    let failures = results.iter().filter(|r| !r.passed).count();
+    let total_ms: u128 = results.iter().map(|r| r.duration_ms).sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"zeckit\" tests=\"{}\" failures=\"{}\" time=\"{}\">",
+        results.len(),
+        failures,
+        seconds(total_ms)
+    );
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"smoke\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"{}\">",
+        results.len(),
+        failures,
+        seconds(total_ms)
+    );
+
+    for result in results {
+        let _ = write!(
+            xml,
+            "    <testcase classname=\"zeckit.smoke\" name=\"{}\" time=\"{}\"",
+            escape_xml(&result.name),
+            seconds(result.duration_ms)
+        );
+
+        if result.passed && result.captured.is_empty() {
+            xml.push_str("/>\n");
+            continue;
+        }
+        xml.push_str(">\n");
+
+        if let Some(error) = result.error.as_deref().filter(|_| !result.passed) {
+            let _ = writeln!(
+                xml,
+                "      <failure message=\"{}\">{}</failure>",
+                escape_xml(error),
+                escape_xml(error)
+            );
+        }
+        if !result.captured.is_empty() {
+            let _ = writeln!(
+                xml,
+                "      <system-out>{}</system-out>",
+                escape_xml(&result.captured.join("\n"))
+            );
+        }
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// TAP version 13, with failure details and captured responses in YAML blocks
+fn tap(results: &[TestResult]) -> String {
+    let mut out = format!("TAP version 13\n1..{}\n", results.len());
+
+    for (i, result) in results.iter().enumerate() {
+        let status = if result.passed { "ok" } else { "not ok" };
+        let _ = writeln!(out, "{} {} - {}", status, i + 1, result.name);
+
+        out.push_str("  ---\n");
+        let _ = writeln!(out, "  duration_ms: {}", result.duration_ms);
+        if result.attempts > 1 {
+            let _ = writeln!(out, "  attempts: {}", result.attempts);
+        }
+        if let Some(error) = result.error.as_deref().filter(|_| !result.passed) {
+            let _ = writeln!(out, "  message: {}", yaml_string(error));
+        }
+        if !result.captured.is_empty() {
+            out.push_str("  captured:\n");
+            for line in &result.captured {
+                let _ = writeln!(out, "    - {}", yaml_string(line));
+            }
+        }
+        out.push_str("  ...\n");
+    }
+
+    out
+}
+
+fn seconds(ms: u128) -> String {
+    format!("{}.{:03}", ms / 1000, ms % 1000)
+}
+
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than tab/newline are not allowed in XML 1.0
+            c if c.is_control() && c != '\n' && c != '\t' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// JSON strings are valid YAML scalars, which saves quoting rules of our own
+fn yaml_string(s: &str) -> String {
+    serde_json::Value::String(s.to_string()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<TestResult> {
+        vec![
+            TestResult {
+                name: "faucet_health".into(),
+                passed: true,
+                attempts: 1,
+                duration_ms: 12,
+                error: None,
+                captured: vec![],
+            },
+            TestResult {
+                name: "shielded_send".into(),
+                passed: false,
+                attempts: 3,
+                duration_ms: 1500,
+                error: Some("Shielded send failed: <insufficient>".into()),
+                captured: vec![r#"POST /send -> {"error":"insufficient \"funds\""}"#.into()],
+            },
+        ]
+    }
+
+    #[test]
+    fn parses_report_specs() {
+        let spec: ReportSpec = "junit=out/results.xml".parse().unwrap();
+        assert_eq!(spec.format, ReportFormat::Junit);
+        assert_eq!(spec.path, PathBuf::from("out/results.xml"));
+
+        assert!("tap=".parse::<ReportSpec>().is_err());
+        assert!("html=report.html".parse::<ReportSpec>().is_err());
+        assert!("results.xml".parse::<ReportSpec>().is_err());
+    }
+
+    #[test]
+    fn junit_escapes_failures_and_captures() {
+        let xml = junit(&sample());
+        assert!(xml.contains("tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<testcase classname=\"zeckit.smoke\" name=\"faucet_health\" time=\"0.012\"/>"));
+        assert!(xml.contains("time=\"1.500\""));
+        assert!(xml.contains("message=\"Shielded send failed: &lt;insufficient&gt;\""));
+        assert!(xml.contains("<system-out>POST /send -&gt; {&quot;error&quot;"));
+    }
+
+    #[test]
+    fn tap_reports_plan_and_diagnostics() {
+        let out = tap(&sample());
+        assert!(out.starts_with("TAP version 13\n1..2\n"));
+        assert!(out.contains("ok 1 - faucet_health\n"));
+        assert!(out.contains("not ok 2 - shielded_send\n"));
+        assert!(out.contains("  duration_ms: 1500\n  attempts: 3\n"));
+        assert!(out.contains("  message: \"Shielded send failed: <insufficient>\"\n"));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::address;
+use crate::config::Settings;
+use crate::error::Result;
+use crate::output;
+use crate::rpc::zebra::RawTransaction;
+use crate::rpc::ZebraRpc;
+use crate::tx::{self, Transaction, TxIn};
+use colored::*;
+use serde::Serialize;
+use zcash_protocol::local_consensus::LocalNetwork;
+
+#[derive(Serialize)]
+struct TxReport {
+    txid: String,
+    height: Option<u64>,
+    confirmations: u64,
+    /// Network upgrade the transaction was built for
+    branch: String,
+    #[serde(flatten)]
+    tx: Transaction,
+    /// Zatoshis spent by each transparent input; unknown for coinbase or unresolvable inputs
+    input_values: Vec<Option<u64>>,
+    output_addresses: Vec<Option<String>>,
+    /// Zatoshis; unknown for coinbase or when an input value is unknown
+    fee: Option<u64>,
+}
+
+pub async fn execute(settings: &Settings, txid: &str) -> Result<()> {
+    let rpc = ZebraRpc::new(settings);
+    let raw = rpc.get_raw_transaction(txid).await?;
+    let (network, tip) = network(&rpc).await?;
+    let decoded = decode(&raw, &network, tip)?;
+    let height = raw.mined_height();
+    let branch = tx::branch_name(decoded.branch_id)
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{:#010x}", decoded.branch_id));
+
+    let mut input_values = Vec::new();
+    for input in &decoded.inputs {
+        input_values.push(prevout_value(&rpc, input, &network, tip).await);
+    }
+    let output_addresses: Vec<Option<String>> = decoded
+        .outputs
+        .iter()
+        .map(|o| hex::decode(&o.script).ok().and_then(|s| address::transparent_from_script(&s)))
+        .collect();
+    let fee = decoded.fee(&input_values);
+
+    result!("{} {}", "Transaction".bold(), txid);
+    match height {
+        Some(h) => result!("  Block:           {} ({} confirmation(s))", h, raw.confirmations.unwrap_or(0)),
+        None => result!("  Block:           {}", "mempool".yellow()),
+    }
+    result!(
+        "  Version:         v{} ({} format)",
+        decoded.version,
+        if decoded.version == 5 { "NU5" } else { "Sapling" }
+    );
+    result!("  Branch:          {}", branch);
+    result!("  Lock time:       {}", decoded.lock_time);
+    result!("  Expiry height:   {}", decoded.expiry_height);
+
+    result!("  Transparent in:  {}", decoded.inputs.len());
+    for (input, value) in decoded.inputs.iter().zip(&input_values) {
+        if input.is_coinbase() {
+            result!("    coinbase");
+        } else {
+            let value = value.map(|v| zec(v as i64)).unwrap_or_else(|| "?".into());
+            result!("    {}:{}  {}", input.prev_txid, input.prev_index, value);
+        }
+    }
+    result!("  Transparent out: {}", decoded.outputs.len());
+    for (i, (out, address)) in decoded.outputs.iter().zip(&output_addresses).enumerate() {
+        let to = address.clone().unwrap_or_else(|| format!("script {}", out.script));
+        result!("    #{} {}  {}", i, zec(out.value as i64), to);
+    }
+
+    result!(
+        "  Sapling:         {} spend(s), {} output(s), value balance {}",
+        decoded.sapling_spends,
+        decoded.sapling_outputs,
+        zec(decoded.sapling_value_balance)
+    );
+    result!(
+        "  Orchard:         {} action(s), value balance {}",
+        decoded.orchard_actions,
+        zec(decoded.orchard_value_balance)
+    );
+    if decoded.joinsplits > 0 {
+        result!(
+            "  Sprout:          {} JoinSplit(s), value balance {}",
+            decoded.joinsplits,
+            zec(decoded.sprout_value_balance)
+        );
+    }
+    match fee {
+        Some(fee) => result!("  Fee:             {}", zec(fee as i64)),
+        None if decoded.is_coinbase() => result!("  Fee:             none (coinbase)"),
+        None => result!("  Fee:             unknown (an input could not be looked up)"),
+    }
+
+    output::emit(&TxReport {
+        txid: txid.to_string(),
+        height,
+        confirmations: raw.confirmations.unwrap_or(0),
+        branch,
+        tx: decoded,
+        input_values,
+        output_addresses,
+        fee,
+    })
+}
+
+/// One-line summary for `zeckit block`
+pub(crate) fn summary(tx: &Transaction) -> String {
+    let mut parts = vec![format!("v{}", tx.version)];
+    if tx.is_coinbase() {
+        parts.push("coinbase".into());
+    }
+    parts.push(format!("{} in / {} out", tx.inputs.len(), tx.outputs.len()));
+    if tx.sapling_spends + tx.sapling_outputs > 0 {
+        parts.push(format!("sapling {}/{}", tx.sapling_spends, tx.sapling_outputs));
+    }
+    if tx.orchard_actions > 0 {
+        parts.push(format!("orchard {}", tx.orchard_actions));
+    }
+    parts.join("  ")
+}
+
+fn zec(zatoshis: i64) -> String {
+    format!("{} ZEC", zatoshis as f64 / 100_000_000.0)
+}
+
+/// Consensus parameters of the running chain, and its tip height
+pub(crate) async fn network(rpc: &ZebraRpc) -> Result<(LocalNetwork, u64)> {
+    let info = rpc.get_blockchain_info().await?;
+    let network = tx::regtest_network(info.upgrades.values().map(|u| (u.name.as_str(), u.activation_height)));
+    Ok((network, info.blocks))
+}
+
+/// Decode `raw` under the rules of the block it was mined in, or of the next
+/// block while it is in the mempool
+fn decode(raw: &RawTransaction, network: &LocalNetwork, tip: u64) -> Result<Transaction> {
+    tx::decode_hex(&raw.hex, network, raw.mined_height().unwrap_or(tip + 1))
+}
+
+/// Value of the output an input spends, looked up from its previous transaction
+async fn prevout_value(rpc: &ZebraRpc, input: &TxIn, network: &LocalNetwork, tip: u64) -> Option<u64> {
+    if input.is_coinbase() {
+        return None;
+    }
+    let prev = rpc.get_raw_transaction(&input.prev_txid).await.ok()?;
+    let prev = decode(&prev, network, tip).ok()?;
+    prev.outputs.get(input.prev_index as usize).map(|o| o.value)
+}
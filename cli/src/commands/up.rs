@@ -1,79 +1,113 @@
+use crate::config::activation::{self, ActivationHeights};
+use crate::config::{Backend, Settings};
 use crate::docker::compose::DockerCompose;
 use crate::docker::health::HealthChecker;
+use crate::docker::volume;
 use crate::error::{Result, ZecKitError};
+use crate::output;
+use crate::rpc::faucet::FaucetStats;
+use crate::rpc::{FaucetApi, ZebraRpc};
+use crate::seed;
+use crate::wait;
 use colored::*;
 use indicatif::{ProgressBar, ProgressStyle};
-use reqwest::Client;
+use serde::Serialize;
 use serde_json::json;
 use std::fs;
-use std::io::{self, Write};
-use tokio::time::{sleep, Duration};
+use std::path::PathBuf;
+use std::time::Duration;
 
-const MAX_WAIT_SECONDS: u64 = 60000;
+/// Where `up` writes the faucet's unified address for tests to pick up,
+/// inside the devnet's fixtures directory
+const UA_FIXTURE: &str = "unified-addresses.json";
 
-// Known transparent address from default seed "abandon abandon abandon..."
-const DEFAULT_FAUCET_ADDRESS: &str = "tmBsTi2xWTjUdEXnuTceL7fecEQKeWaPDJd";
+pub async fn execute(settings: &Settings, fresh: bool, rebootstrap: bool, offline: bool) -> Result<()> {
+    let backend = settings.backend;
 
-pub async fn execute(backend: String, fresh: bool) -> Result<()> {
-    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
-    println!("{}", "  ZecKit - Starting Devnet".cyan().bold());
-    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
-    println!();
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!("{}", "  ZecKit - Starting Devnet".cyan().bold());
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!();
+    
+    if let Some(name) = &settings.name {
+        say!("Devnet: {} (compose project {})", name.bold(), settings.container_prefix());
+        say!();
+    }
+    
+    super::doctor::preflight(settings)?;
     
-    let compose = DockerCompose::new()?;
+    let compose = DockerCompose::new(settings)?.offline(offline);
+    let services = backend.services();
+    check_profile(&compose, backend, &services)?;
     
     if fresh {
-        println!("{}", "🧹 Cleaning up old data (fresh start)...".yellow());
+        say!("{}", "🧹 Cleaning up old data (fresh start)...".yellow());
         compose.down(true)?;
     }
     
-    let services = match backend.as_str() {
-        "lwd" => vec!["zebra", "faucet"],
-        "zaino" => vec!["zebra", "faucet"],
-        "none" => vec!["zebra", "faucet"],
-        _ => {
-            return Err(ZecKitError::Config(format!(
-                "Invalid backend: {}. Use 'lwd', 'zaino', or 'none'", 
-                backend
-            )));
-        }
-    };
+    // A devnet that is already up is reused; only unsatisfied steps run again
+    let already_running = !fresh
+        && compose.is_running()
+        && services.iter().all(|service| compose.is_service_running(service));
     
-    println!("Starting services: {}", services.join(", "));
-    println!();
+    say!("Starting services: {}", services.join(", "));
+    if !backend.has_faucet() {
+        say!("{}", "  Node-only mode: no indexer or faucet (use --backend lwd|zaino for a funded wallet)".dimmed());
+    }
+    say!();
     
     // ========================================================================
-    // STEP 1: Pre-configure zebra.toml BEFORE starting any containers
+    // STEP 1: Render this devnet's zebra.toml BEFORE starting any containers
     // ========================================================================
-    println!("📝 Configuring Zebra mining address...");
-    
-    match update_zebra_config_file(DEFAULT_FAUCET_ADDRESS) {
-        Ok(_) => {
-            println!("✓ Updated docker/configs/zebra.toml");
-            println!("  Mining to: {}", DEFAULT_FAUCET_ADDRESS);
-        }
-        Err(e) => {
-            println!("{}", format!("Warning: Could not update zebra.toml: {}", e).yellow());
-            println!("  Using existing config");
-        }
+    say!("📝 Configuring Zebra mining address and activation heights...");
+    
+    // Mine to the faucet wallet's first transparent address, so block rewards
+    // land in the wallet whichever seed it was created with
+    let passphrase = settings.faucet.passphrase.as_deref().unwrap_or_default();
+    let miner_address = seed::transparent_address(&faucet_seed(settings, &compose)?, passphrase, 0, 0)?;
+    
+    // A stale miner_address would send every block reward to someone else's wallet
+    let zebra_config = settings.zebra_config();
+    let changed = update_zebra_config_file(settings, &miner_address).map_err(|e| {
+        ZecKitError::Config(format!("Could not configure the Zebra miner address: {}", e))
+    })?;
+    if !changed {
+        say!("✓ {} already up to date", zebra_config.display());
+    } else if already_running {
+        say!("✓ Updated {}", zebra_config.display());
+        say!("{}", "⚠ Zebra is running with the previous config; run `zeckit down` then `zeckit up` to apply it".yellow());
+    } else {
+        say!("✓ Updated {}", zebra_config.display());
+    }
+    say!("  Mining to: {}", miner_address);
+    say!("  Upgrades:  {}", describe_activation(&settings.activation));
+    if settings.zebra.rpc_auth {
+        say!("  RPC auth:  cookie");
     }
-    println!();
+    say!();
     
     // ========================================================================
     // STEP 2: Build and start services (smart build - only when needed)
     // ========================================================================
-    if backend == "lwd" {
-        compose.up_with_profile("lwd", fresh)?;
-        println!();
-    } else if backend == "zaino" {
-        compose.up_with_profile("zaino", fresh)?;
-        println!();
+    if already_running {
+        say!("✓ Devnet already running; reusing it (--fresh to start over)");
+        say!();
     } else {
-        compose.up(&services)?;
+        // Name every missing image up front rather than failing on the first one
+        if offline {
+            compose.require_images(backend.profile())?;
+        }
+        match backend.profile() {
+            Some(profile) => {
+                compose.up_with_profile(profile, fresh)?;
+                say!();
+            }
+            None => compose.up(&services)?,
+        }
     }
     
-    println!("Starting services...");
-    println!();
+    say!("Starting services...");
+    say!();
     
     let pb = ProgressBar::new_spinner();
     pb.set_style(
@@ -85,272 +119,298 @@ pub async fn execute(backend: String, fresh: bool) -> Result<()> {
     // ========================================================================
     // STEP 3: Wait for Zebra
     // ========================================================================
-    let checker = HealthChecker::new();
-    let start = std::time::Instant::now();
+    let checker = HealthChecker::new(settings);
+    let total_steps = services.len() - 1; // the miner has no readiness check
+    let faucet_step = total_steps;
     
-    loop {
-        pb.tick();
-        
-        if checker.wait_for_zebra(&pb).await.is_ok() {
-            println!("[1/3] Zebra ready (100%)");
-            break;
-        }
-        
-        let elapsed = start.elapsed().as_secs();
-        if elapsed < 120 {
-            let progress = (elapsed as f64 / 120.0 * 100.0).min(99.0) as u32;
-            print!("\r[1/3] Starting Zebra... {}%", progress);
-            io::stdout().flush().ok();
-            sleep(Duration::from_secs(1)).await;
-        } else {
-            return Err(ZecKitError::ServiceNotReady("Zebra not ready".into()));
-        }
+    say_inline!("[1/{}] Starting Zebra...", total_steps);
+    checker.wait_for_zebra(&pb).await?;
+    say!("\r[1/{}] Zebra ready      ", total_steps);
+    
+    if let Err(e) = verify_activation_heights(settings).await {
+        say!("{}", format!("⚠ {}", e).yellow());
+        say!("{}", "  Activation heights only apply to a new chain; restart with --fresh".yellow());
     }
-    println!();
     
     // ========================================================================
     // STEP 4: Wait for Backend (if using lwd or zaino)
     // ========================================================================
-    if backend == "lwd" || backend == "zaino" {
-        let backend_name = if backend == "lwd" { "Lightwalletd" } else { "Zaino" };
-        let start = std::time::Instant::now();
-        
-        loop {
-            pb.tick();
-            
-            if checker.wait_for_backend(&backend, &pb).await.is_ok() {
-                println!("[2/3] {} ready (100%)", backend_name);
-                break;
-            }
-            
-            let elapsed = start.elapsed().as_secs();
-            if elapsed < 180 {
-                let progress = (elapsed as f64 / 180.0 * 100.0).min(99.0) as u32;
-                print!("\r[2/3] Starting {}... {}%", backend_name, progress);
-                io::stdout().flush().ok();
-                sleep(Duration::from_secs(1)).await;
-            } else {
-                return Err(ZecKitError::ServiceNotReady(format!("{} not ready", backend_name)));
-            }
-        }
-        println!();
+    if backend.service().is_some() {
+        let backend_name = backend.display_name();
+        say_inline!("[2/{}] Starting {}...", total_steps, backend_name);
+        let info = checker.wait_for_backend(backend, &pb).await?;
+        say!("\r[2/{}] {} ready at height {}      ", total_steps, backend_name, info.block_height);
+        say!("      {} {} (chain {})", info.vendor, info.version, info.chain_name);
     }
     
     // ========================================================================
-    // STEP 5: Wait for Faucet
+    // STEP 5: Wait for Faucet (if the backend has one)
     // ========================================================================
-    let start = std::time::Instant::now();
-    loop {
-        pb.tick();
-        
-        if checker.wait_for_faucet(&pb).await.is_ok() {
-            println!("[3/3] Faucet ready (100%)");
-            break;
-        }
-        
-        let elapsed = start.elapsed().as_secs();
-        if elapsed < 120 {
-            let progress = (elapsed as f64 / 120.0 * 100.0).min(99.0) as u32;
-            print!("\r[3/3] Starting Faucet... {}%", progress);
-            io::stdout().flush().ok();
-            sleep(Duration::from_secs(1)).await;
-        } else {
-            return Err(ZecKitError::ServiceNotReady("Faucet not ready".into()));
-        }
+    if backend.has_faucet() {
+        say_inline!("[{}/{}] Starting Faucet...", faucet_step, total_steps);
+        checker.wait_for_faucet(&pb).await?;
+        say!("\r[{}/{}] Faucet ready      ", faucet_step, total_steps);
     }
-    println!();
     
     pb.finish_and_clear();
     
     // ========================================================================
-    // STEP 6: Verify wallet address matches configured address
+    // STEP 6: Verify wallet address matches configured address (faucet only)
     // ========================================================================
-    println!();
-    println!("🔍 Verifying wallet configuration...");
-    
-    match get_wallet_transparent_address_from_faucet().await {
-        Ok(addr) => {
-            println!("✓ Faucet wallet address: {}", addr);
-            if addr != DEFAULT_FAUCET_ADDRESS {
-                println!("{}", format!("⚠ Warning: Address mismatch!").yellow());
-                println!("{}", format!("  Expected: {}", DEFAULT_FAUCET_ADDRESS).yellow());
-                println!("{}", format!("  Got:      {}", addr).yellow());
-                println!("{}", "  This may cause funds to be lost!".yellow());
-            } else {
-                println!("✓ Address matches Zebra mining configuration");
+    if backend.has_faucet() {
+        say!();
+        say!("🔍 Verifying wallet configuration...");
+    
+        match FaucetApi::new(settings).addresses().await {
+            Ok(addresses) => {
+                let addr = addresses.transparent_address;
+                say!("✓ Faucet wallet address: {}", addr);
+                if addr != miner_address {
+                    return Err(ZecKitError::Config(format!(
+                        "Faucet wallet address {} does not match the Zebra miner address {}; \
+                         block rewards would not reach the faucet. Restart with --fresh to \
+                         recreate the wallet from the configured seed",
+                        addr, miner_address
+                    )));
+                }
+                say!("✓ Address matches Zebra mining configuration");
+            }
+            Err(e) => {
+                say!("{}", format!("Warning: Could not verify wallet address: {}", e).yellow());
             }
         }
-        Err(e) => {
-            println!("{}", format!("Warning: Could not verify wallet address: {}", e).yellow());
-        }
+        say!();
+    
     }
-    println!();
     
     // ========================================================================
     // STEP 7: Mine initial blocks
     // ========================================================================
-    wait_for_mined_blocks(&pb, 101).await?;
+    wait_for_mined_blocks(settings).await?;
     
     // ========================================================================
     // STEP 8: Mine additional blocks for full maturity
     // ========================================================================
-    println!();
-    println!("Mining additional blocks for maturity...");
-    mine_additional_blocks(100).await?;
+    // Coinbase outputs need 100 confirmations before the faucet can spend them
+    let mature_height = settings.mining.initial_blocks + settings.mining.maturity_blocks as u64;
+    let height = ZebraRpc::new(settings).get_block_count().await?;
+    say!();
+    if rebootstrap {
+        say!("Mining additional blocks for maturity...");
+        mine_additional_blocks(settings, settings.mining.maturity_blocks).await?;
+    } else if height < mature_height {
+        say!("Mining additional blocks for maturity...");
+        mine_additional_blocks(settings, (mature_height - height) as u32).await?;
+    } else {
+        say!("✓ Chain already at height {} (matures at {}); skipping maturity mining", height, mature_height);
+    }
+    
+    let final_balance = if !backend.has_faucet() {
+        None
+    } else if let Some(balance) = existing_bootstrap(settings, rebootstrap).await {
+        say!();
+        say!(
+            "✓ Faucet wallet already funded ({} ZEC in Orchard); skipping wallet bootstrap (--rebootstrap to force)",
+            balance.orchard
+        );
+        Some(balance)
+    } else {
+        bootstrap_faucet_wallet(settings).await
+    };
     
     // ========================================================================
-    // STEP 9: Wait for blocks to propagate
+    // STEP 14: Make sure the background miner container is running
     // ========================================================================
-    println!();
-    println!("Waiting for blocks to propagate...");
-    sleep(Duration::from_secs(10)).await;
+    say!();
+    say!("Checking background miner (1 block every {}s)...", settings.mining.interval_secs);
+    if !compose.is_service_running("miner") {
+        compose.start_service("miner")?;
+    }
+    say!("✓ Miner running in container {}-miner", settings.container_prefix());
+    
+    print_connection_info(settings);
+    let block_height = print_mining_info(settings).await;
+    
+    say!();
+    say!("{}", "✓ Devnet is running with continuous mining".green().bold());
+    say!("{}", format!("   New blocks will be mined every {} seconds", settings.mining.interval_secs).green());
+    say!("{}", "   Control mining with: zeckit miner stop|start|status|rate <secs>".green());
+    
+    output::emit(&UpReport {
+        backend,
+        zebra_rpc_url: settings.zebra_rpc_url(),
+        faucet_api_url: backend.has_faucet().then(|| settings.faucet_api_url()),
+        backend_url: backend.service().map(|_| settings.backend_url()),
+        block_height,
+        miner_interval_secs: settings.mining.interval_secs,
+        faucet_balance: final_balance,
+        reused: already_running,
+    })
+}
+
+/// Balance of a faucet wallet an earlier `up` already bootstrapped: fixtures
+/// written and funds shielded. `None` means steps 9-13 still need to run.
+async fn existing_bootstrap(settings: &Settings, rebootstrap: bool) -> Option<BalanceReport> {
+    if rebootstrap || !ua_fixture(settings).ok()?.exists() {
+        return None;
+    }
+    
+    let stats = FaucetApi::new(settings).stats().await.ok()?;
+    (stats.orchard_balance > 0.0).then_some(BalanceReport {
+        transparent: stats.transparent_balance,
+        orchard: stats.orchard_balance,
+        total: stats.current_balance,
+    })
+}
+
+/// Steps 9-13: fixtures, wallet sync and shielding through the faucet.
+/// Returns the final wallet balance, if it could be read.
+async fn bootstrap_faucet_wallet(settings: &Settings) -> Option<BalanceReport> {
+    let faucet = FaucetApi::new(settings);
+    let zebra = ZebraRpc::new(settings);
+    let sync_timeout = Duration::from_secs(settings.timeouts.sync_secs);
     
     // ========================================================================
-    // STEP 10: Generate UA fixtures from faucet API
+    // STEP 9: Generate UA fixtures from faucet API
     // ========================================================================
-    println!();
-    println!("Generating ZIP-316 Unified Address fixtures...");
+    say!();
+    say!("Generating ZIP-316 Unified Address fixtures...");
     
-    match generate_ua_fixtures_from_faucet().await {
+    match generate_ua_fixtures_from_faucet(settings, &faucet).await {
         Ok(address) => {
-            println!("Generated UA: {}...", &address[..20]);
+            say!("Generated UA: {}...", &address[..20]);
         }
         Err(e) => {
-            println!("{}", format!("Warning: Could not generate UA fixture ({})", e).yellow());
+            say!("{}", format!("Warning: Could not generate UA fixture ({})", e).yellow());
         }
     }
     
     // ========================================================================
-    // STEP 11: Sync wallet through faucet API
+    // STEP 10: Sync wallet up to Zebra's tip
     // ========================================================================
-    println!();
-    println!("Syncing wallet with blockchain...");
-    
-    // Give wallet time to catch up with mined blocks
-    sleep(Duration::from_secs(5)).await;
-    
-    if let Err(e) = sync_wallet_via_faucet().await {
-        println!("{}", format!("Wallet sync warning: {}", e).yellow());
-        println!("  Will retry after waiting...");
-        sleep(Duration::from_secs(10)).await;
-        
-        // Retry once
-        if let Err(e) = sync_wallet_via_faucet().await {
-            println!("{}", format!("Wallet sync still failing: {}", e).yellow());
-        } else {
-            println!("✓ Wallet synced on retry");
-        }
-    } else {
-        println!("✓ Wallet synced with blockchain");
+    say!();
+    say!("Syncing wallet with blockchain...");
+    match wait::wallet_synced(&faucet, &zebra, sync_timeout).await {
+        Ok(height) => say!("✓ Wallet synced to height {}", height),
+        Err(e) => say!("{}", format!("Wallet sync warning: {}", e).yellow()),
     }
     
-    // Wait for sync to complete
-    sleep(Duration::from_secs(5)).await;
-    
     // ========================================================================
-    // STEP 12: Check balance BEFORE shielding
+    // STEP 11: Check balance BEFORE shielding
     // ========================================================================
-    println!();
-    println!("Checking transparent balance...");
-    match check_wallet_balance().await {
-        Ok((transparent, orchard, total)) => {
-            println!("  Transparent: {} ZEC", transparent);
-            println!("  Orchard: {} ZEC", orchard);
-            println!("  Total: {} ZEC", total);
+    say!();
+    say!("Checking transparent balance...");
+    match faucet.stats().await {
+        Ok(FaucetStats { transparent_balance: transparent, orchard_balance: orchard, current_balance: total, .. }) => {
+            say!("  Transparent: {} ZEC", transparent);
+            say!("  Orchard: {} ZEC", orchard);
+            say!("  Total: {} ZEC", total);
             
             if transparent == 0.0 && total == 0.0 {
-                println!();
-                println!("{}", "⚠ WARNING: Wallet has no funds!".yellow().bold());
-                println!("{}", "  This means Zebra did NOT mine to the faucet wallet address.".yellow());
-                println!("{}", "  Possible causes:".yellow());
-                println!("{}", "    1. Zebra config wasn't updated properly".yellow());
-                println!("{}", "    2. Wallet seed mismatch".yellow());
-                println!("{}", "  The devnet will still work, but the faucet won't have funds.".yellow());
+                say!();
+                say!("{}", "⚠ WARNING: Wallet has no funds!".yellow().bold());
+                say!("{}", "  This means Zebra did NOT mine to the faucet wallet address.".yellow());
+                say!("{}", "  Possible causes:".yellow());
+                say!("{}", "    1. Zebra config wasn't updated properly".yellow());
+                say!("{}", "    2. Wallet seed mismatch".yellow());
+                say!("{}", "  The devnet will still work, but the faucet won't have funds.".yellow());
             }
         }
         Err(e) => {
-            println!("{}", format!("Could not check balance: {}", e).yellow());
+            say!("{}", format!("Could not check balance: {}", e).yellow());
         }
     }
     
     // ========================================================================
-    // STEP 13: Shield transparent funds to orchard
+    // STEP 12: Shield transparent funds to orchard
     // ========================================================================
-    println!();
-    if let Err(e) = shield_transparent_funds().await {
-        println!("{}", format!("Shield operation: {}", e).yellow());
+    say!();
+    if let Err(e) = shield_transparent_funds(&faucet, &zebra, settings).await {
+        say!("{}", format!("Shield operation: {}", e).yellow());
     } else {
-        // Sync again after shielding
-        println!("Re-syncing after shielding...");
-        sleep(Duration::from_secs(15)).await;
-        
-        if let Err(e) = sync_wallet_via_faucet().await {
-            println!("{}", format!("Warning: Post-shield sync failed: {}", e).yellow());
-        } else {
-            println!("✓ Post-shield sync complete");
+        say!("Re-syncing after shielding...");
+        match wait::wallet_synced(&faucet, &zebra, sync_timeout).await {
+            Ok(height) => say!("✓ Post-shield sync complete at height {}", height),
+            Err(e) => say!("{}", format!("Warning: Post-shield sync failed: {}", e).yellow()),
         }
-        
-        sleep(Duration::from_secs(5)).await;
     }
     
     // ========================================================================
-    // STEP 14: Final balance check
+    // STEP 13: Final balance check
     // ========================================================================
-    println!();
-    println!("Final wallet balance:");
-    match check_wallet_balance().await {
-        Ok((transparent, orchard, total)) => {
-            println!("  Transparent: {} ZEC", transparent);
-            println!("  Orchard: {} ZEC", orchard);
-            println!("  Total: {} ZEC", total);
+    say!();
+    say!("Final wallet balance:");
+    match faucet.stats().await {
+        Ok(FaucetStats { transparent_balance: transparent, orchard_balance: orchard, current_balance: total, .. }) => {
+            say!("  Transparent: {} ZEC", transparent);
+            say!("  Orchard: {} ZEC", orchard);
+            say!("  Total: {} ZEC", total);
             
             if total > 0.0 {
-                println!();
-                println!("{}", "✓ Faucet wallet funded and ready!".green().bold());
+                say!();
+                say!("{}", "✓ Faucet wallet funded and ready!".green().bold());
             }
+            Some(BalanceReport { transparent, orchard, total })
         }
         Err(e) => {
-            println!("{}", format!("Could not check balance: {}", e).yellow());
+            say!("{}", format!("Could not check balance: {}", e).yellow());
+            None
         }
     }
-    
-    // ========================================================================
-    // STEP 15: Start background miner
-    // ========================================================================
-    println!();
-    println!("Starting continuous background miner (1 block every 15s)...");
-    start_background_miner().await?;
-    
-    print_connection_info(&backend);
-    print_mining_info().await?;
-    
-    println!();
-    println!("{}", "✓ Devnet is running with continuous mining".green().bold());
-    println!("{}", "   New blocks will be mined every 15 seconds".green());
-    println!("{}", "   Press Ctrl+C to stop".green());
-    
-    Ok(())
+}
+
+#[derive(Serialize)]
+struct UpReport {
+    backend: Backend,
+    zebra_rpc_url: String,
+    faucet_api_url: Option<String>,
+    backend_url: Option<String>,
+    block_height: Option<u64>,
+    miner_interval_secs: u64,
+    faucet_balance: Option<BalanceReport>,
+    /// The devnet was already running and was reused
+    reused: bool,
+}
+
+#[derive(Serialize)]
+struct BalanceReport {
+    transparent: f64,
+    orchard: f64,
+    total: f64,
+}
+
+/// Seed phrase the faucet wallet uses: the configured mnemonic, else the one it
+/// saved in its data volume on an earlier run, else the default seed
+fn faucet_seed(settings: &Settings, compose: &DockerCompose) -> Result<String> {
+    if let Some(phrase) = &settings.faucet.mnemonic {
+        return Ok(phrase.clone());
+    }
+
+    let volume_name = volume::compose_name(&compose.project_name()?, "faucet-data");
+    if volume::exists(&volume_name) {
+        if let Some(phrase) = volume::read_file(&volume_name, seed::SEED_FILE)? {
+            return Ok(phrase.trim().to_string());
+        }
+    }
+    Ok(seed::DEFAULT_SEED_PHRASE.to_string())
 }
 
 // ============================================================================
-// NEW FUNCTION: Update zebra.toml on host before starting containers
+// Zebra configuration
 // ============================================================================
-fn update_zebra_config_file(address: &str) -> Result<()> {
+/// Render this devnet's zebra.toml from docker/configs/zebra.toml, pointed at
+/// `address`, the activation heights and the RPC auth setting; returns whether
+/// the rendered file had to change
+fn update_zebra_config_file(settings: &Settings, address: &str) -> Result<bool> {
     use regex::Regex;
     
-    // Get project root (same logic as DockerCompose::new())
-    let current_dir = std::env::current_dir()?;
-    let project_dir = if current_dir.ends_with("cli") {
-        current_dir.parent().unwrap().to_path_buf()
-    } else {
-        current_dir.clone()
-    };
-    
-    let config_path = project_dir.join("docker/configs/zebra.toml");
+    let project_dir = crate::utils::project_dir()?;
+    let template_path = project_dir.join("docker/configs/zebra.toml");
+    let config_path = project_dir.join(settings.zebra_config());
     
-    // Read current config
-    let config = fs::read_to_string(&config_path)
-        .map_err(|e| ZecKitError::Config(format!("Could not read {:?}: {}", config_path, e)))?;
+    // Read the shared template
+    let config = fs::read_to_string(&template_path)
+        .map_err(|e| ZecKitError::Config(format!("Could not read {:?}: {}", template_path, e)))?;
     
     // Update miner address using regex
     let updated = if config.contains("miner_address") {
@@ -371,182 +431,137 @@ fn update_zebra_config_file(address: &str) -> Result<()> {
         }
     };
     
-    // Write back to file
+    let updated = activation::render_zebra_config(&updated, &settings.activation);
+    
+    let re = Regex::new(r"enable_cookie_auth\s*=\s*(true|false)")
+        .map_err(|e| ZecKitError::Config(format!("Regex error: {}", e)))?;
+    if !re.is_match(&updated) {
+        return Err(ZecKitError::Config(format!("{:?} has no [rpc] enable_cookie_auth setting", template_path)));
+    }
+    let updated = re.replace(&updated, format!("enable_cookie_auth = {}", settings.zebra.rpc_auth)).to_string();
+    
+    if fs::read_to_string(&config_path).is_ok_and(|current| current == updated) {
+        return Ok(false);
+    }
+    
+    if let Some(parent) = config_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
     fs::write(&config_path, updated)
         .map_err(|e| ZecKitError::Config(format!("Could not write {:?}: {}", config_path, e)))?;
     
-    Ok(())
+    Ok(true)
+}
+
+/// `nu5@1, nu6@150` style summary of the active upgrades
+fn describe_activation(activation: &ActivationHeights) -> String {
+    activation
+        .iter()
+        .filter_map(|(_, name, height)| height.map(|h| format!("{}@{}", name, h)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Compare the upgrades Zebra reports with the configured heights. The indexers
+/// take consensus parameters from Zebra, so this is the one place they can drift.
+async fn verify_activation_heights(settings: &Settings) -> Result<()> {
+    let info = ZebraRpc::new(settings).get_blockchain_info().await?;
+    let reported: Vec<(String, u64)> = info
+        .upgrades
+        .into_values()
+        .map(|u| (u.name, u.activation_height))
+        .collect();
+    
+    let mismatches: Vec<String> = settings
+        .activation
+        .iter()
+        .filter_map(|(_, name, expected)| {
+            let actual = reported
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, h)| *h);
+            (actual != expected.map(u64::from)).then(|| {
+                let show = |h: Option<u64>| h.map(|h| h.to_string()).unwrap_or_else(|| "off".into());
+                format!("{} (configured {}, Zebra {})", name, show(expected.map(u64::from)), show(actual))
+            })
+        })
+        .collect();
+    
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(ZecKitError::Config(format!(
+            "Zebra activation heights differ from config: {}",
+            mismatches.join(", ")
+        )))
+    }
 }
 
 // ============================================================================
-// Helper Functions (keep all your existing functions below)
+// Helper Functions
 // ============================================================================
 
-async fn wait_for_mined_blocks(_pb: &ProgressBar, min_blocks: u64) -> Result<()> {
-    let client = Client::new();
-    let start = std::time::Instant::now();
-    
-    println!("Mining initial blocks...");
-    
-    loop {
-        match get_block_count(&client).await {
-            Ok(height) if height >= min_blocks => {
-                println!("✓ Mined {} blocks", height);
-                println!();
-                return Ok(());
-            }
-            Ok(height) => {
-                let progress = (height as f64 / min_blocks as f64 * 100.0) as u64;
-                print!("\r  Block {} / {} ({}%)", height, min_blocks, progress);
-                io::stdout().flush().ok();
-            }
-            Err(_) => {}
-        }
-        
-        if start.elapsed().as_secs() > MAX_WAIT_SECONDS {
-            return Err(ZecKitError::ServiceNotReady(
-                "Internal miner timeout - blocks not reaching maturity".into()
-            ));
-        }
-        
-        sleep(Duration::from_secs(2)).await;
-    }
+async fn wait_for_mined_blocks(settings: &Settings) -> Result<()> {
+    let rpc = ZebraRpc::new(settings);
+    let min_blocks = settings.mining.initial_blocks;
+    
+    say!("Mining initial blocks...");
+    
+    let height = wait::chain_height(
+        &rpc,
+        min_blocks,
+        Duration::from_secs(settings.timeouts.mining_secs),
+        |height| {
+            let progress = (height as f64 / min_blocks as f64 * 100.0).min(100.0) as u64;
+            say_inline!("\r  Block {} / {} ({}%)", height, min_blocks, progress);
+        },
+    )
+    .await?;
+    
+    say!("\r✓ Mined {} blocks              ", height);
+    say!();
+    Ok(())
 }
 
-async fn mine_additional_blocks(count: u32) -> Result<()> {
-    let client = Client::new();
+async fn mine_additional_blocks(settings: &Settings, count: u32) -> Result<()> {
+    let rpc = ZebraRpc::new(settings);
     
-    println!("Mining {} additional blocks...", count);
+    say!("Mining {} additional blocks...", count);
     
     for i in 1..=count {
-        let _ = client
-            .post("http://127.0.0.1:8232")
-            .json(&json!({
-                "jsonrpc": "2.0",
-                "id": "generate",
-                "method": "generate",
-                "params": [1]
-            }))
-            .timeout(Duration::from_secs(10))
-            .send()
-            .await;
+        rpc.generate(1).await?;
         
         if i % 10 == 0 {
-            print!("\r  Mined {} / {} blocks", i, count);
-            io::stdout().flush().ok();
+            say_inline!("\r  Mined {} / {} blocks", i, count);
         }
     }
     
-    println!("\n✓ Mined {} additional blocks", count);
-    Ok(())
-}
-
-async fn start_background_miner() -> Result<()> {
-    tokio::spawn(async {
-        let client = Client::new();
-        let mut interval = tokio::time::interval(Duration::from_secs(15));
-        
-        loop {
-            interval.tick().await;
-            
-            let _ = client
-                .post("http://127.0.0.1:8232")
-                .json(&json!({
-                    "jsonrpc": "2.0",
-                    "id": "bgminer",
-                    "method": "generate",
-                    "params": [1]
-                }))
-                .timeout(Duration::from_secs(10))
-                .send()
-                .await;
-        }
-    });
-    
+    say!("\n✓ Mined {} additional blocks", count);
     Ok(())
 }
 
-async fn shield_transparent_funds() -> Result<()> {
-    let client = Client::new();
-    
-    println!("Shielding transparent funds to Orchard...");
+async fn shield_transparent_funds(faucet: &FaucetApi, zebra: &ZebraRpc, settings: &Settings) -> Result<()> {
+    say!("Shielding transparent funds to Orchard...");
     
-    let resp = client
-        .post("http://127.0.0.1:8080/shield")
-        .timeout(Duration::from_secs(60))
-        .send()
-        .await?;
-    
-    let json: serde_json::Value = resp.json().await?;
-    
-    if json["status"] == "no_funds" {
+    let shielded = faucet.shield().await?;
+    let Some(txid) = shielded.txid else {
         return Err(ZecKitError::HealthCheck("No transparent funds to shield".into()));
-    }
-    
-    if let Some(txid) = json.get("txid").and_then(|v| v.as_str()) {
-        println!("✓ Shielded {} ZEC", json["transparent_amount"].as_f64().unwrap_or(0.0));
-        println!("  Transaction ID: {}", txid);
-        println!("  Waiting for confirmation...");
-        sleep(Duration::from_secs(20)).await;
-        return Ok(());
-    }
+    };
     
-    Err(ZecKitError::HealthCheck("Shield transaction failed".into()))
-}
-
-async fn get_block_count(client: &Client) -> Result<u64> {
-    let resp = client
-        .post("http://127.0.0.1:8232")
-        .json(&json!({
-            "jsonrpc": "2.0",
-            "id": "blockcount",
-            "method": "getblockcount",
-            "params": []
-        }))
-        .timeout(Duration::from_secs(5))
-        .send()
-        .await?;
-    
-    let json: serde_json::Value = resp.json().await?;
-    
-    json.get("result")
-        .and_then(|v| v.as_u64())
-        .ok_or_else(|| ZecKitError::HealthCheck("Invalid block count response".into()))
+    say!("✓ Shielded {} ZEC", shielded.transparent_amount);
+    say!("  Transaction ID: {}", txid);
+    say!("  Waiting for confirmation...");
+    let height = wait::transaction_mined(zebra, &txid, Duration::from_secs(settings.timeouts.tx_secs)).await?;
+    say!("✓ Mined at height {}", height);
+    Ok(())
 }
 
-async fn get_wallet_transparent_address_from_faucet() -> Result<String> {
-    let client = Client::new();
-    
-    let resp = client
-        .get("http://127.0.0.1:8080/address")
-        .timeout(Duration::from_secs(10))
-        .send()
-        .await
-        .map_err(|e| ZecKitError::HealthCheck(format!("Faucet API call failed: {}", e)))?;
-    
-    let json: serde_json::Value = resp.json().await?;
-    
-    json.get("transparent_address")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| ZecKitError::HealthCheck("No transparent address in faucet response".into()))
-        .map(|s| s.to_string())
+fn ua_fixture(settings: &Settings) -> Result<PathBuf> {
+    Ok(crate::utils::project_dir()?.join(settings.fixtures_dir()).join(UA_FIXTURE))
 }
 
-async fn generate_ua_fixtures_from_faucet() -> Result<String> {
-    let client = Client::new();
-    
-    let resp = client
-        .get("http://127.0.0.1:8080/address")
-        .timeout(Duration::from_secs(10))
-        .send()
-        .await
-        .map_err(|e| ZecKitError::HealthCheck(format!("Faucet API call failed: {}", e)))?;
-    
-    let json: serde_json::Value = resp.json().await?;
-    
-    let ua_address = json.get("unified_address")
-        .and_then(|v| v.as_str())
-        .ok_or_else(|| ZecKitError::HealthCheck("No unified address in faucet response".into()))?;
+async fn generate_ua_fixtures_from_faucet(settings: &Settings, faucet: &FaucetApi) -> Result<String> {
+    let ua_address = faucet.addresses().await?.unified_address;
     
     let fixture = json!({
         "faucet_address": ua_address,
@@ -554,89 +569,87 @@ async fn generate_ua_fixtures_from_faucet() -> Result<String> {
         "receivers": ["orchard"]
     });
     
-    fs::create_dir_all("fixtures")?;
+    let path = ua_fixture(settings)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
     fs::write(
-        "fixtures/unified-addresses.json",
+        path,
         serde_json::to_string_pretty(&fixture)?
     )?;
     
-    Ok(ua_address.to_string())
+    Ok(ua_address)
 }
 
-async fn sync_wallet_via_faucet() -> Result<()> {
-    let client = Client::new();
-    
-    let resp = client
-        .post("http://127.0.0.1:8080/sync")
-        .timeout(Duration::from_secs(60))
-        .send()
-        .await
-        .map_err(|e| ZecKitError::HealthCheck(format!("Faucet sync failed: {}", e)))?;
-    
-    if !resp.status().is_success() {
-        let status = resp.status();
-        let body = resp.text().await.unwrap_or_default();
-        return Err(ZecKitError::HealthCheck(
-            format!("Wallet sync failed ({}): {}", status, body)
-        ));
+async fn print_mining_info(settings: &Settings) -> Option<u64> {
+    let height = ZebraRpc::new(settings).get_block_count().await.ok();
+    if let Some(height) = height {
+        say!();
+        say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+        say!("{}", "  Blockchain Status".cyan().bold());
+        say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+        say!();
+        say!("  Block Height: {}", height);
+        say!("  Network: Regtest");
+        say!("  Mining: Continuous (1 block / {}s)", settings.mining.interval_secs);
     }
     
-    Ok(())
+    height
 }
 
-async fn check_wallet_balance() -> Result<(f64, f64, f64)> {
-    let client = Client::new();
-    let resp = client
-        .get("http://127.0.0.1:8080/stats")
-        .timeout(Duration::from_secs(5))
-        .send()
-        .await?;
-    
-    let json: serde_json::Value = resp.json().await?;
-    
-    let transparent = json["transparent_balance"].as_f64().unwrap_or(0.0);
-    let orchard = json["orchard_balance"].as_f64().unwrap_or(0.0);
-    let total = json["current_balance"].as_f64().unwrap_or(0.0);
-    
-    Ok((transparent, orchard, total))
-}
+fn print_connection_info(settings: &Settings) {
+    let backend = settings.backend;
+    let faucet_url = settings.faucet_api_url();
 
-async fn print_mining_info() -> Result<()> {
-    let client = Client::new();
-    
-    if let Ok(height) = get_block_count(&client).await {
-        println!();
-        println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
-        println!("{}", "  Blockchain Status".cyan().bold());
-        println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
-        println!();
-        println!("  Block Height: {}", height);
-        println!("  Network: Regtest");
-        println!("  Mining: Continuous (1 block / 15s)");
+    say!();
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!("{}", "  Services Ready".cyan().bold());
+    say!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    say!();
+    say!("  Zebra RPC: {}", settings.zebra_rpc_url());
+    
+    if backend.has_faucet() {
+        say!("  Faucet API: {}", faucet_url);
+    }
+    if backend.service().is_some() {
+        say!("  {}: {}", backend.display_name(), settings.backend_url());
     }
     
-    Ok(())
+    say!();
+    say!("Next steps:");
+    if backend.has_faucet() {
+        say!("  • Check balance: curl {}/stats", faucet_url);
+        say!("  • View fixtures: cat {}", settings.fixtures_dir().join(UA_FIXTURE).display());
+        say!("  • Request funds: zeckit fund <address> --wait-confirmations 1");
+    } else {
+        say!("  • Mine blocks: zeckit mine 10");
+        say!("  • Add a funded wallet: zeckit up --backend lwd (or zaino)");
+    }
+    say!();
 }
 
-fn print_connection_info(backend: &str) {
-    println!();
-    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
-    println!("{}", "  Services Ready".cyan().bold());
-    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
-    println!();
-    println!("  Zebra RPC: http://127.0.0.1:8232");
-    println!("  Faucet API: http://127.0.0.1:8080");
-    
-    if backend == "lwd" {
-        println!("  LightwalletD: http://127.0.0.1:9067");
-    } else if backend == "zaino" {
-        println!("  Zaino: http://127.0.0.1:9067");
+/// Fail early, naming the missing service, when docker-compose.yml cannot
+/// provide everything the selected backend needs
+fn check_profile(compose: &DockerCompose, backend: Backend, services: &[&str]) -> Result<()> {
+    let defined = compose.services(backend.profile())?;
+    let missing: Vec<&str> = services
+        .iter()
+        .copied()
+        .filter(|service| !defined.iter().any(|d| d == service))
+        .collect();
+
+    if missing.is_empty() {
+        return Ok(());
     }
-    
-    println!();
-    println!("Next steps:");
-    println!("  • Check balance: curl http://127.0.0.1:8080/stats");
-    println!("  • View fixtures: cat fixtures/unified-addresses.json");
-    println!("  • Request funds: curl -X POST http://127.0.0.1:8080/request -d '{{\"address\":\"...\"}}'");
-    println!();
-}
\ No newline at end of file
+
+    let profile = match backend.profile() {
+        Some(profile) => format!(" (compose profile '{}')", profile),
+        None => String::new(),
+    };
+    Err(ZecKitError::Config(format!(
+        "Backend '{}' needs service(s) {} but docker-compose.yml does not define them{}",
+        backend,
+        missing.join(", "),
+        profile
+    )))
+}
@@ -0,0 +1,159 @@
+use crate::address::validate_regtest_address;
+use crate::commands::fund::wait_for_confirmations;
+use crate::config::Settings;
+use crate::error::{Result, ZecKitError};
+use crate::output;
+use crate::rpc::faucet::{HistoryEntry, SendResponse, ShieldResponse, WalletAddresses};
+use crate::rpc::FaucetApi;
+use clap::Subcommand;
+use colored::*;
+use serde::Serialize;
+
+#[derive(Subcommand)]
+pub enum WalletAction {
+    /// Show the faucet wallet balance per pool
+    Balance,
+    /// Show the faucet wallet's unified and transparent addresses
+    Addresses,
+    /// Sync the faucet wallet with the chain tip
+    Sync,
+    /// Shield transparent funds into the Orchard pool
+    Shield,
+    /// Send from the faucet wallet's Orchard pool
+    Send {
+        /// Regtest address to send to (transparent, Sapling or unified)
+        address: String,
+
+        /// Amount in ZEC
+        amount: f64,
+
+        /// Memo to attach (shielded recipients only)
+        #[arg(short, long)]
+        memo: Option<String>,
+
+        /// Mine blocks until the transaction has this many confirmations
+        #[arg(short, long, default_value_t = 0)]
+        wait_confirmations: u64,
+    },
+    /// Show recent faucet payouts
+    History {
+        /// Number of transactions to show
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+    },
+}
+
+#[derive(Serialize)]
+struct BalanceReport {
+    transparent: f64,
+    sapling: f64,
+    orchard: f64,
+    total: f64,
+}
+
+#[derive(Serialize)]
+struct SendReport {
+    #[serde(flatten)]
+    sent: SendResponse,
+    address_type: String,
+    confirmations: u64,
+}
+
+pub async fn execute(settings: &Settings, action: WalletAction) -> Result<()> {
+    let faucet = FaucetApi::new(settings);
+
+    match action {
+        WalletAction::Balance => {
+            let stats = faucet.stats().await?;
+            let report = BalanceReport {
+                transparent: stats.transparent_balance,
+                sapling: stats.sapling_balance,
+                orchard: stats.orchard_balance,
+                total: stats.current_balance,
+            };
+
+            result!("{}", "Faucet wallet balance".bold());
+            result!("  Transparent: {} ZEC", report.transparent);
+            result!("  Sapling:     {} ZEC", report.sapling);
+            result!("  Orchard:     {} ZEC", report.orchard);
+            result!("  {}", format!("Total:       {} ZEC", report.total).bold());
+            output::emit(&report)
+        }
+        WalletAction::Addresses => {
+            let addresses: WalletAddresses = faucet.addresses().await?;
+            result!("Unified:     {}", addresses.unified_address);
+            result!("Transparent: {}", addresses.transparent_address);
+            output::emit(&addresses)
+        }
+        WalletAction::Sync => {
+            say!("Syncing faucet wallet...");
+            let synced = faucet.sync().await?;
+            match synced.synced_height {
+                Some(height) => result!("{} Wallet synced to height {}", "✓".green(), height),
+                None => result!("{} Wallet synced", "✓".green()),
+            }
+            output::emit(&synced)
+        }
+        WalletAction::Shield => {
+            let shielded: ShieldResponse = faucet.shield().await?;
+            match &shielded.txid {
+                Some(txid) => {
+                    result!(
+                        "{} Shielded {} ZEC to Orchard (fee {} ZEC)",
+                        "✓".green(),
+                        shielded.shielded_amount,
+                        shielded.fee
+                    );
+                    result!("  TXID: {}", txid);
+                }
+                None => result!("No transparent funds to shield"),
+            }
+            output::emit(&shielded)
+        }
+        WalletAction::Send { address, amount, memo, wait_confirmations } => {
+            if amount <= 0.0 {
+                return Err(ZecKitError::Config("Amount must be greater than zero".into()));
+            }
+            let kind = validate_regtest_address(&address)?;
+            say!("Sending {} ZEC to {} address {}...", amount, kind, address);
+
+            let sent = faucet.send(&address, amount, memo.as_deref()).await?;
+            result!("{} Sent {} ZEC to {}", "✓".green(), sent.amount, sent.to_address);
+            result!("  TXID: {}", sent.txid);
+            result!("  Orchard balance: {} ZEC", sent.orchard_balance);
+
+            let confirmations = if wait_confirmations > 0 {
+                wait_for_confirmations(settings, &sent.txid, wait_confirmations).await?
+            } else {
+                0
+            };
+
+            output::emit(&SendReport {
+                sent,
+                address_type: kind.to_string(),
+                confirmations,
+            })
+        }
+        WalletAction::History { limit } => {
+            let history: Vec<HistoryEntry> = faucet.history(limit.max(1)).await?;
+            if history.is_empty() {
+                result!("No faucet transactions yet");
+            } else {
+                result!(
+                    "{}",
+                    format!("  {:<25} {:>12}  {:<64}  {}", "TIME", "AMOUNT", "TXID", "TO").bold()
+                );
+                for tx in &history {
+                    result!(
+                        "  {:<25} {:>12}  {:<64}  {}",
+                        tx.timestamp.split('.').next().unwrap_or(&tx.timestamp),
+                        format!("{} ZEC", tx.amount),
+                        tx.txid,
+                        tx.to_address
+                    );
+                }
+            }
+            output::emit(&history)
+        }
+    }
+}
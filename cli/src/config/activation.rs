@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+
+/// Section of zebra.toml holding the regtest activation heights
+pub const ZEBRA_SECTION: &str = "[network.testnet_parameters.activation_heights]";
+
+/// Network upgrades in activation order: key used in zeckit.toml / `--activate`,
+/// and the name Zebra uses in its config and `getblockchaininfo`
+pub const UPGRADES: &[(&str, &str)] = &[
+    ("overwinter", "Overwinter"),
+    ("sapling", "Sapling"),
+    ("blossom", "Blossom"),
+    ("heartwood", "Heartwood"),
+    ("canopy", "Canopy"),
+    ("nu5", "NU5"),
+    ("nu6", "NU6"),
+    ("nu6_1", "NU6.1"),
+    ("nu7", "NU7"),
+];
+
+/// Regtest network upgrade activation heights, declared once and rendered into
+/// zebra.toml and the faucet's wallet. `None` leaves an upgrade inactive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ActivationHeights {
+    pub overwinter: Option<u32>,
+    pub sapling: Option<u32>,
+    pub blossom: Option<u32>,
+    pub heartwood: Option<u32>,
+    pub canopy: Option<u32>,
+    pub nu5: Option<u32>,
+    pub nu6: Option<u32>,
+    #[serde(alias = "nu6.1")]
+    pub nu6_1: Option<u32>,
+    pub nu7: Option<u32>,
+}
+
+impl Default for ActivationHeights {
+    /// Everything up to NU5 from block 1, later upgrades off
+    fn default() -> Self {
+        Self {
+            overwinter: Some(1),
+            sapling: Some(1),
+            blossom: Some(1),
+            heartwood: Some(1),
+            canopy: Some(1),
+            nu5: Some(1),
+            nu6: None,
+            nu6_1: None,
+            nu7: None,
+        }
+    }
+}
+
+impl ActivationHeights {
+    fn slot(&mut self, key: &str) -> Option<&mut Option<u32>> {
+        match key {
+            "overwinter" => Some(&mut self.overwinter),
+            "sapling" => Some(&mut self.sapling),
+            "blossom" => Some(&mut self.blossom),
+            "heartwood" => Some(&mut self.heartwood),
+            "canopy" => Some(&mut self.canopy),
+            "nu5" => Some(&mut self.nu5),
+            "nu6" => Some(&mut self.nu6),
+            "nu6_1" | "nu6.1" => Some(&mut self.nu6_1),
+            "nu7" => Some(&mut self.nu7),
+            _ => None,
+        }
+    }
+
+    /// `(key, zebra name, height)` for every upgrade, in activation order
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &'static str, Option<u32>)> {
+        let heights = [
+            self.overwinter,
+            self.sapling,
+            self.blossom,
+            self.heartwood,
+            self.canopy,
+            self.nu5,
+            self.nu6,
+            self.nu6_1,
+            self.nu7,
+        ];
+        UPGRADES
+            .iter()
+            .zip(heights)
+            .map(|((key, name), height)| (*key, *name, height))
+    }
+
+    /// Apply one `--activate` value: `nu6=150`, or `nu7=off` to deactivate
+    pub fn apply(&mut self, spec: &str) -> Result<(), String> {
+        let (key, value) = spec
+            .split_once('=')
+            .ok_or_else(|| format!("expected <upgrade>=<height>, got '{}'", spec))?;
+        let key = key.trim().to_lowercase();
+
+        let height = match value.trim() {
+            "off" | "none" => None,
+            value => Some(
+                value
+                    .parse::<u32>()
+                    .map_err(|_| format!("invalid height '{}' for {}", value, key))?,
+            ),
+        };
+
+        let names = UPGRADES.iter().map(|(k, _)| *k).collect::<Vec<_>>().join(", ");
+        let slot = self
+            .slot(&key)
+            .ok_or_else(|| format!("unknown upgrade '{}' (use one of: {})", key, names))?;
+        *slot = height;
+        Ok(())
+    }
+
+    /// Upgrades must activate in order: none may be set after a later one is
+    /// off, heights may not decrease, and nothing activates at genesis
+    pub fn validate(&self) -> Result<(), String> {
+        let mut previous: Option<(&str, u32)> = None;
+        let mut first_off: Option<&str> = None;
+
+        for (key, _, height) in self.iter() {
+            match (height, first_off) {
+                (None, None) => first_off = Some(key),
+                (None, Some(_)) => {}
+                (Some(_), Some(off)) => {
+                    return Err(format!("{} is set but the earlier upgrade {} is off", key, off));
+                }
+                (Some(0), None) => return Err(format!("{} cannot activate at height 0", key)),
+                (Some(h), None) => {
+                    if let Some((prev_key, prev_h)) = previous {
+                        if h < prev_h {
+                            return Err(format!(
+                                "{} ({}) activates before {} ({})",
+                                key, h, prev_key, prev_h
+                            ));
+                        }
+                    }
+                    previous = Some((key, h));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Body of the zebra.toml activation section
+    pub fn zebra_section(&self) -> String {
+        self.iter()
+            .filter_map(|(_, name, height)| {
+                let key = if name.contains('.') { format!("\"{}\"", name) } else { name.to_string() };
+                height.map(|h| format!("{} = {}\n", key, h))
+            })
+            .collect()
+    }
+
+    /// Compact `key=height,...` form passed to containers as `ZECKIT_ACTIVATION_HEIGHTS`
+    pub fn env_value(&self) -> String {
+        self.iter()
+            .filter_map(|(key, _, height)| height.map(|h| format!("{}={}", key, h)))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Replace (or append) the activation section of a zebra.toml, leaving the rest untouched
+pub fn render_zebra_config(config: &str, heights: &ActivationHeights) -> String {
+    let section = format!("{}\n{}", ZEBRA_SECTION, heights.zebra_section());
+
+    let Some(start) = config.find(ZEBRA_SECTION) else {
+        return format!("{}\n\n{}", config.trim_end(), section);
+    };
+
+    // The section runs until the next table header
+    let after = start + ZEBRA_SECTION.len();
+    let end = config[after..]
+        .find("\n[")
+        .map(|i| after + i + 1)
+        .unwrap_or(config.len());
+
+    let rest = &config[end..];
+    let separator = if rest.is_empty() { "" } else { "\n" };
+    format!("{}{}{}{}", &config[..start], section, separator, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_activate_flags() {
+        let mut heights = ActivationHeights::default();
+        heights.apply("nu6=150").unwrap();
+        heights.apply("NU6.1=200").unwrap();
+        assert_eq!(heights.nu6, Some(150));
+        assert_eq!(heights.nu6_1, Some(200));
+
+        heights.apply("nu6_1=off").unwrap();
+        assert_eq!(heights.nu6_1, None);
+
+        assert!(heights.apply("nu9=1").is_err());
+        assert!(heights.apply("nu6").is_err());
+        assert!(heights.apply("nu6=soon").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_order_upgrades() {
+        let mut heights = ActivationHeights::default();
+        assert!(heights.validate().is_ok());
+
+        heights.nu7 = Some(300);
+        assert!(heights.validate().is_err(), "nu7 without nu6");
+
+        heights.nu6 = Some(150);
+        heights.nu6_1 = Some(100);
+        assert!(heights.validate().is_err(), "nu6.1 before nu6");
+
+        heights.nu6_1 = Some(150);
+        assert!(heights.validate().is_ok());
+    }
+
+    #[test]
+    fn renders_zebra_section_in_place() {
+        let config = "[network]\nnetwork = \"Regtest\"\n\n[network.testnet_parameters.activation_heights]\nNU5 = 1\n\n[mining]\ninternal_miner = true\n";
+        let heights = ActivationHeights {
+            nu6: Some(150),
+            nu6_1: Some(160),
+            ..Default::default()
+        };
+
+        let rendered = render_zebra_config(config, &heights);
+        assert!(rendered.starts_with("[network]\nnetwork = \"Regtest\"\n\n[network.testnet_parameters.activation_heights]\nOverwinter = 1\n"));
+        assert!(rendered.contains("NU5 = 1\nNU6 = 150\n\"NU6.1\" = 160\n\n[mining]\ninternal_miner = true\n"));
+        assert!(!rendered.contains("NU7"));
+
+        // Rendering is idempotent, and a missing section is appended
+        assert_eq!(render_zebra_config(&rendered, &heights), rendered);
+        assert!(render_zebra_config("[mining]\n", &heights).contains("[mining]\n\n[network.testnet_parameters.activation_heights]\n"));
+    }
+
+    #[test]
+    fn env_value_lists_active_upgrades() {
+        let heights = ActivationHeights {
+            nu6: Some(150),
+            ..Default::default()
+        };
+        assert_eq!(
+            heights.env_value(),
+            "overwinter=1,sapling=1,blossom=1,heartwood=1,canopy=1,nu5=1,nu6=150"
+        );
+    }
+}
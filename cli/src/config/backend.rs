@@ -0,0 +1,116 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Light-client backend the devnet runs, and with it the compose profile and faucet variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    /// lightwalletd (compose profile `lwd`)
+    #[serde(alias = "lightwalletd")]
+    #[value(alias = "lightwalletd")]
+    Lwd,
+    /// Zaino indexer (compose profile `zaino`)
+    Zaino,
+    /// Node only: Zebra and the miner, without an indexer or faucet
+    #[default]
+    None,
+}
+
+/// Services started for every backend
+const CORE_SERVICES: &[&str] = &["zebra", "miner"];
+
+impl Backend {
+    /// Compose profile holding the backend and its faucet
+    pub fn profile(self) -> Option<&'static str> {
+        match self {
+            Backend::Lwd => Some("lwd"),
+            Backend::Zaino => Some("zaino"),
+            Backend::None => None,
+        }
+    }
+
+    /// Compose service of the indexer itself
+    pub fn service(self) -> Option<&'static str> {
+        match self {
+            Backend::Lwd => Some("lightwalletd"),
+            Backend::Zaino => Some("zaino"),
+            Backend::None => None,
+        }
+    }
+
+    /// Compose service of the faucet wired to this backend. The faucet syncs
+    /// through the indexer, so node-only mode has none.
+    pub fn faucet_service(self) -> Option<&'static str> {
+        match self {
+            Backend::Lwd => Some("faucet-lwd"),
+            Backend::Zaino => Some("faucet-zaino"),
+            Backend::None => None,
+        }
+    }
+
+    pub fn has_faucet(self) -> bool {
+        self.faucet_service().is_some()
+    }
+
+    /// Every compose service that makes up a devnet with this backend
+    pub fn services(self) -> Vec<&'static str> {
+        CORE_SERVICES
+            .iter()
+            .copied()
+            .chain(self.service())
+            .chain(self.faucet_service())
+            .collect()
+    }
+
+    /// Name shown in progress output
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Backend::Lwd => "Lightwalletd",
+            Backend::Zaino => "Zaino",
+            Backend::None => "none",
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Backend::Lwd => "lwd",
+            Backend::Zaino => "zaino",
+            Backend::None => "none",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        <Backend as ValueEnum>::from_str(s, true)
+            .map_err(|_| format!("invalid backend '{}' (use lwd, zaino or none)", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn services_follow_backend() {
+        assert_eq!(Backend::Lwd.services(), ["zebra", "miner", "lightwalletd", "faucet-lwd"]);
+        assert_eq!(Backend::Zaino.services(), ["zebra", "miner", "zaino", "faucet-zaino"]);
+        assert_eq!(Backend::None.services(), ["zebra", "miner"]);
+        assert!(!Backend::None.has_faucet());
+    }
+
+    #[test]
+    fn parses_names_and_aliases() {
+        assert_eq!("lwd".parse::<Backend>(), Ok(Backend::Lwd));
+        assert_eq!("lightwalletd".parse::<Backend>(), Ok(Backend::Lwd));
+        assert_eq!("Zaino".parse::<Backend>(), Ok(Backend::Zaino));
+        assert!("zcashd".parse::<Backend>().is_err());
+    }
+}
@@ -0,0 +1,215 @@
+use super::settings::Ports;
+use super::{Backend, Settings};
+use crate::error::{Result, ZecKitError};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// Port sets are spaced this far apart, so devnet `k` uses the base ports + `k * PORT_STRIDE`
+const PORT_STRIDE: u16 = 10;
+/// How many port sets to try before giving up
+const MAX_SLOTS: u16 = 100;
+
+/// A named devnet, remembered so its ports stay reserved while it is stopped
+/// and other commands can find it with `--name`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Devnet {
+    pub name: String,
+    pub backend: Backend,
+    pub ports: Ports,
+    /// Project directory holding the docker-compose.yml it was started from
+    pub project_dir: PathBuf,
+}
+
+impl Devnet {
+    /// Registered devnet called `name`, if any
+    pub fn load(name: &str) -> Result<Option<Self>> {
+        let path = registry_dir()?.join(format!("{}.toml", name));
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = fs::read_to_string(&path)?;
+        toml::from_str(&contents)
+            .map(Some)
+            .map_err(|e| ZecKitError::Config(format!("Invalid devnet record {:?}: {}", path, e)))
+    }
+
+    /// Every registered devnet, by name
+    pub fn list() -> Result<Vec<Self>> {
+        let dir = registry_dir()?;
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut names: Vec<String> = fs::read_dir(&dir)?
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|ext| ext == "toml"))
+            .filter_map(|p| p.file_stem().map(|s| s.to_string_lossy().to_string()))
+            .collect();
+        names.sort();
+
+        let mut devnets = Vec::new();
+        for name in names {
+            devnets.extend(Self::load(&name)?);
+        }
+        Ok(devnets)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let dir = registry_dir()?;
+        fs::create_dir_all(&dir)?;
+
+        let contents = toml::to_string_pretty(self)
+            .map_err(|e| ZecKitError::Config(format!("Could not serialize devnet: {}", e)))?;
+        fs::write(dir.join(format!("{}.toml", self.name)), contents)?;
+        Ok(())
+    }
+
+    pub fn remove(name: &str) -> Result<()> {
+        let path = registry_dir()?.join(format!("{}.toml", name));
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// Register `name` with the first port set, offset from `settings.ports`,
+    /// that no other devnet has reserved and nothing on the host is listening on
+    fn allocate(name: &str, settings: &Settings) -> Result<Self> {
+        let reserved: Vec<Ports> = Self::list()?.into_iter().map(|d| d.ports).collect();
+
+        for slot in 1..=MAX_SLOTS {
+            let offset = slot * PORT_STRIDE;
+            let ports = Ports {
+                zebra_rpc: settings.ports.zebra_rpc.saturating_add(offset),
+                zebra_p2p: settings.ports.zebra_p2p.saturating_add(offset),
+                backend: settings.ports.backend.saturating_add(offset),
+                faucet: settings.ports.faucet.saturating_add(offset),
+            };
+
+            let clashes = reserved.iter().any(|r| overlaps(r, &ports));
+            if !clashes && port_list(&ports).iter().all(|p| crate::utils::port_is_free(settings.host, *p)) {
+                return Ok(Self {
+                    name: name.to_string(),
+                    backend: settings.backend,
+                    ports,
+                    project_dir: crate::utils::project_dir()?,
+                });
+            }
+        }
+
+        Err(ZecKitError::Config(format!(
+            "No free port set found for devnet '{}' after {} attempts",
+            name, MAX_SLOTS
+        )))
+    }
+}
+
+/// Point `settings` at its named devnet, if it has one.
+///
+/// With `create` (used by `zeckit up`) an unknown name gets a fresh port set
+/// and the chosen backend is recorded; otherwise the devnet must already exist
+/// and its backend and ports are used.
+pub fn resolve(settings: &mut Settings, create: bool) -> Result<Option<Devnet>> {
+    let Some(name) = settings.name.clone() else {
+        return Ok(None);
+    };
+    validate_name(&name)?;
+
+    let devnet = match (Devnet::load(&name)?, create) {
+        (Some(mut devnet), true) => {
+            devnet.backend = settings.backend;
+            devnet.project_dir = crate::utils::project_dir()?;
+            devnet.save()?;
+            devnet
+        }
+        (Some(devnet), false) => {
+            settings.backend = devnet.backend;
+            devnet
+        }
+        (None, true) => {
+            let devnet = Devnet::allocate(&name, settings)?;
+            devnet.save()?;
+            devnet
+        }
+        (None, false) => {
+            return Err(ZecKitError::Config(format!(
+                "No devnet named '{}' (start it with: zeckit up --name {})",
+                name, name
+            )));
+        }
+    };
+
+    settings.ports = devnet.ports.clone();
+    Ok(Some(devnet))
+}
+
+/// Names become part of compose project, volume and container names
+fn validate_name(name: &str) -> Result<()> {
+    let valid = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ZecKitError::Config(format!(
+            "Invalid devnet name '{}': use lowercase letters, digits, '-' and '_'",
+            name
+        )))
+    }
+}
+
+/// Per-user, so devnets from different checkouts on one host never share ports
+fn registry_dir() -> Result<PathBuf> {
+    if let Some(home) = std::env::var_os("ZECKIT_HOME") {
+        return Ok(PathBuf::from(home).join("devnets"));
+    }
+
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".zeckit").join("devnets"))
+        .ok_or_else(|| ZecKitError::Config("Cannot locate home directory (set ZECKIT_HOME)".into()))
+}
+
+fn port_list(ports: &Ports) -> [u16; 4] {
+    [ports.zebra_rpc, ports.zebra_p2p, ports.backend, ports.faucet]
+}
+
+fn overlaps(a: &Ports, b: &Ports) -> bool {
+    let b = port_list(b);
+    port_list(a).iter().any(|p| b.contains(p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_must_be_compose_safe() {
+        assert!(validate_name("feature-x").is_ok());
+        assert!(validate_name("ci_42").is_ok());
+        assert!(validate_name("Feature").is_err());
+        assert!(validate_name("-x").is_err());
+        assert!(validate_name("a/b").is_err());
+        assert!(validate_name("").is_err());
+    }
+
+    #[test]
+    fn overlapping_port_sets_are_detected() {
+        let base = Ports::default();
+        let shifted = Ports {
+            zebra_rpc: 8242,
+            zebra_p2p: 8243,
+            backend: 9077,
+            faucet: 8090,
+        };
+        assert!(!overlaps(&base, &shifted));
+        assert!(overlaps(&base, &Ports { faucet: 8233, ..shifted }));
+    }
+}
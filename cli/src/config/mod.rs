@@ -1 +1,7 @@
-pub mod settings;
\ No newline at end of file
+pub mod activation;
+pub mod backend;
+pub mod devnet;
+pub mod settings;
+
+pub use backend::Backend;
+pub use settings::Settings;
@@ -1,26 +1,457 @@
+use super::activation::ActivationHeights;
+use super::Backend;
+use crate::error::{Result, ZecKitError};
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
 
-#[allow(dead_code)]
+/// Name of the project-level configuration file
+pub const CONFIG_FILE_NAME: &str = "zeckit.toml";
+
+/// Files generated for each devnet, relative to the project root
+const DEVNETS_DIR: &str = ".zeckit/devnets";
+
+/// Devnet settings loaded from `zeckit.toml`, environment variables and CLI flags
+/// (in increasing order of precedence). Every field has a default, so the file
+/// only needs to contain the values that differ.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Settings {
-    pub zebra_rpc_url: String,
-    pub faucet_api_url: String,
-    pub backend_url: String,
+    /// Devnet name; named devnets get their own compose project, containers and ports
+    pub name: Option<String>,
+    /// Light-client backend: lwd (lightwalletd), zaino or none
+    pub backend: Backend,
+    /// Address the devnet ports are published on (docker needs an IP, not a hostname)
+    pub host: IpAddr,
+    pub ports: Ports,
+    pub timeouts: Timeouts,
+    pub mining: Mining,
+    /// Network upgrade activation heights, shared by Zebra and the faucet wallet
+    pub activation: ActivationHeights,
+    pub faucet: Faucet,
+    pub zebra: Zebra,
+}
+
+/// Zebra RPC authentication
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Zebra {
+    /// Turn on Zebra's cookie authentication, as production nodes run it
+    pub rpc_auth: bool,
+    /// Cookie file to authenticate with; by default it is read from the running Zebra container
+    pub cookie_file: Option<PathBuf>,
+}
+
+/// Faucet wallet identity. Without a mnemonic the faucet uses the shared
+/// regtest seed, so every installation mines to the same wallet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Faucet {
+    /// BIP-39 mnemonic of the faucet wallet
+    #[serde(skip_serializing)]
+    pub mnemonic: Option<String>,
+    /// File holding the mnemonic, used when `mnemonic` is not set
+    pub mnemonic_file: Option<PathBuf>,
+    /// Optional BIP-39 passphrase
+    #[serde(skip_serializing)]
+    pub passphrase: Option<String>,
+    /// Block height a new wallet starts scanning from
+    pub birthday: u32,
+    /// Accounts created in a new wallet
+    pub accounts: u32,
+}
+
+/// Host ports published by docker-compose
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Ports {
+    pub zebra_rpc: u16,
+    pub zebra_p2p: u16,
+    pub backend: u16,
+    pub faucet: u16,
+}
+
+/// Deadlines, in seconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Timeouts {
+    /// How long to wait for Zebra RPC to come up
+    pub zebra_secs: u64,
+    /// How long to wait for lightwalletd / Zaino to come up
+    pub backend_secs: u64,
+    /// How long to wait for the faucet API to come up
+    pub faucet_secs: u64,
+    /// How long to wait for the initial blocks to be mined
+    pub mining_secs: u64,
+    /// Timeout for a single RPC / API request
+    pub request_secs: u64,
+    /// Timeout for long-running wallet operations (sync, shield)
+    pub wallet_secs: u64,
+    /// How long to wait for the faucet wallet to reach Zebra's tip
+    pub sync_secs: u64,
+    /// How long to wait for a transaction to be mined
+    pub tx_secs: u64,
+}
+
+/// Block generation during bootstrap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Mining {
+    /// Blocks the internal miner must produce before bootstrap continues
+    pub initial_blocks: u64,
+    /// Extra blocks generated so coinbase outputs mature
+    pub maturity_blocks: u32,
+    /// Seconds between blocks produced by the background miner container
+    pub interval_secs: u64,
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
-            zebra_rpc_url: "http://127.0.0.1:8232".to_string(),
-            faucet_api_url: "http://127.0.0.1:8080".to_string(),
-            backend_url: "http://127.0.0.1:9067".to_string(),
+            name: None,
+            backend: Backend::default(),
+            host: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            ports: Ports::default(),
+            timeouts: Timeouts::default(),
+            mining: Mining::default(),
+            activation: ActivationHeights::default(),
+            faucet: Faucet::default(),
+            zebra: Zebra::default(),
+        }
+    }
+}
+
+impl Default for Faucet {
+    fn default() -> Self {
+        Self {
+            mnemonic: None,
+            mnemonic_file: None,
+            passphrase: None,
+            birthday: 0,
+            accounts: 1,
+        }
+    }
+}
+
+impl Default for Ports {
+    fn default() -> Self {
+        Self {
+            zebra_rpc: 8232,
+            zebra_p2p: 8233,
+            backend: 9067,
+            faucet: 8080,
+        }
+    }
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self {
+            zebra_secs: 1120,
+            backend_secs: 1800,
+            faucet_secs: 1120,
+            mining_secs: 60000,
+            request_secs: 10,
+            wallet_secs: 60,
+            sync_secs: 300,
+            tx_secs: 120,
+        }
+    }
+}
+
+impl Default for Mining {
+    fn default() -> Self {
+        Self {
+            initial_blocks: 101,
+            maturity_blocks: 100,
+            interval_secs: 15,
         }
     }
 }
 
-#[allow(dead_code)]
 impl Settings {
-    pub fn new() -> Self {
-        Self::default()
+    /// Load settings for the current project.
+    ///
+    /// The config file is taken from `config_path` (the `--config` flag), then
+    /// `ZECKIT_CONFIG`, then `zeckit.toml` in the project root. An explicitly
+    /// requested file must exist; the project file is optional.
+    pub fn load(config_path: Option<&Path>) -> Result<Self> {
+        let explicit = config_path
+            .map(Path::to_path_buf)
+            .or_else(|| std::env::var_os("ZECKIT_CONFIG").map(PathBuf::from));
+
+        let mut settings = match explicit {
+            Some(path) => Self::from_file(&path)?,
+            None => {
+                let path = crate::utils::project_dir()?.join(CONFIG_FILE_NAME);
+                if path.exists() {
+                    Self::from_file(&path)?
+                } else {
+                    Self::default()
+                }
+            }
+        };
+
+        settings.apply_env_overrides()?;
+        settings.faucet.resolve_mnemonic()?;
+        settings
+            .activation
+            .validate()
+            .map_err(|e| ZecKitError::Config(format!("Invalid activation heights: {}", e)))?;
+        Ok(settings)
     }
-}
\ No newline at end of file
+
+    fn from_file(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| ZecKitError::Config(format!("Could not read {:?}: {}", path, e)))?;
+
+        Self::from_toml(&contents)
+            .map_err(|e| ZecKitError::Config(format!("Invalid {:?}: {}", path, e)))
+    }
+
+    fn from_toml(contents: &str) -> std::result::Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    /// Environment variables use the same names docker-compose interpolates,
+    /// so exporting e.g. `ZECKIT_FAUCET_PORT` moves both the container and the CLI.
+    fn apply_env_overrides(&mut self) -> Result<()> {
+        if let Ok(name) = std::env::var("ZECKIT_NAME") {
+            self.name = Some(name);
+        }
+        override_from_env("ZECKIT_BACKEND", &mut self.backend)?;
+        override_from_env("ZECKIT_HOST", &mut self.host)?;
+
+        override_from_env("ZECKIT_ZEBRA_RPC_PORT", &mut self.ports.zebra_rpc)?;
+        override_from_env("ZECKIT_ZEBRA_P2P_PORT", &mut self.ports.zebra_p2p)?;
+        override_from_env("ZECKIT_BACKEND_PORT", &mut self.ports.backend)?;
+        override_from_env("ZECKIT_FAUCET_PORT", &mut self.ports.faucet)?;
+        override_from_env("ZECKIT_INITIAL_BLOCKS", &mut self.mining.initial_blocks)?;
+        override_from_env("ZECKIT_MINER_INTERVAL", &mut self.mining.interval_secs)?;
+
+        if let Ok(mnemonic) = std::env::var("ZECKIT_FAUCET_MNEMONIC") {
+            self.faucet.mnemonic = Some(mnemonic).filter(|m| !m.trim().is_empty());
+        }
+        if let Ok(path) = std::env::var("ZECKIT_FAUCET_MNEMONIC_FILE") {
+            self.faucet.mnemonic = None;
+            self.faucet.mnemonic_file = Some(PathBuf::from(path));
+        }
+        if let Ok(passphrase) = std::env::var("ZECKIT_FAUCET_PASSPHRASE") {
+            self.faucet.passphrase = Some(passphrase).filter(|p| !p.is_empty());
+        }
+        override_from_env("ZECKIT_FAUCET_BIRTHDAY", &mut self.faucet.birthday)?;
+        override_from_env("ZECKIT_FAUCET_ACCOUNTS", &mut self.faucet.accounts)?;
+        if self.faucet.accounts == 0 {
+            return Err(ZecKitError::Config("faucet.accounts must be at least 1".into()));
+        }
+
+        override_from_env("ZECKIT_RPC_AUTH", &mut self.zebra.rpc_auth)?;
+        if let Ok(path) = std::env::var("ZECKIT_ZEBRA_COOKIE_FILE") {
+            self.zebra.cookie_file = Some(PathBuf::from(path)).filter(|p| !p.as_os_str().is_empty());
+        }
+
+        // Same syntax as `--activate`, comma-separated: ZECKIT_ACTIVATE=nu6=150,nu6_1=200
+        if let Ok(specs) = std::env::var("ZECKIT_ACTIVATE") {
+            for spec in specs.split(',').filter(|s| !s.trim().is_empty()) {
+                self.activation
+                    .apply(spec)
+                    .map_err(|e| ZecKitError::Config(format!("Invalid ZECKIT_ACTIVATE: {}", e)))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn zebra_rpc_url(&self) -> String {
+        format!("http://{}", SocketAddr::new(self.host, self.ports.zebra_rpc))
+    }
+
+    pub fn faucet_api_url(&self) -> String {
+        format!("http://{}", SocketAddr::new(self.host, self.ports.faucet))
+    }
+
+    pub fn backend_url(&self) -> String {
+        format!("http://{}", SocketAddr::new(self.host, self.ports.backend))
+    }
+
+    /// Compose project of a named devnet; unnamed devnets keep compose's default
+    pub fn compose_project(&self) -> Option<String> {
+        self.name.as_ref().map(|name| format!("zeckit-{}", name))
+    }
+
+    /// Prefix of every `container_name` in docker-compose.yml
+    pub fn container_prefix(&self) -> String {
+        self.compose_project().unwrap_or_else(|| "zeckit".to_string())
+    }
+
+    /// This devnet's generated files, relative to the project root; one
+    /// directory per compose project so devnets never share them
+    pub fn devnet_dir(&self) -> PathBuf {
+        Path::new(DEVNETS_DIR).join(self.container_prefix())
+    }
+
+    /// zebra.toml rendered for this devnet, relative to the project root
+    pub fn zebra_config(&self) -> PathBuf {
+        self.devnet_dir().join("zebra.toml")
+    }
+
+    /// Fixtures `up` writes for tests, relative to the project root: `fixtures/`
+    /// for the default devnet, inside `devnet_dir` for named ones
+    pub fn fixtures_dir(&self) -> PathBuf {
+        match self.name {
+            Some(_) => self.devnet_dir().join("fixtures"),
+            None => PathBuf::from("fixtures"),
+        }
+    }
+
+    /// Variables passed to `docker compose` so published ports, project and
+    /// container names follow the config
+    pub fn compose_env(&self) -> Vec<(&'static str, String)> {
+        let mut env = vec![
+            // Compose's `host:port:port` syntax wants IPv6 addresses in brackets
+            (
+                "ZECKIT_HOST",
+                match self.host {
+                    IpAddr::V4(host) => host.to_string(),
+                    IpAddr::V6(host) => format!("[{}]", host),
+                },
+            ),
+            ("ZECKIT_ZEBRA_RPC_PORT", self.ports.zebra_rpc.to_string()),
+            ("ZECKIT_ZEBRA_P2P_PORT", self.ports.zebra_p2p.to_string()),
+            ("ZECKIT_BACKEND_PORT", self.ports.backend.to_string()),
+            ("ZECKIT_FAUCET_PORT", self.ports.faucet.to_string()),
+            ("ZECKIT_MINER_INTERVAL", self.mining.interval_secs.to_string()),
+            ("ZECKIT_CONTAINER_PREFIX", self.container_prefix()),
+            ("ZECKIT_ZEBRA_CONFIG", Path::new(".").join(self.zebra_config()).display().to_string()),
+            ("ZECKIT_ACTIVATION_HEIGHTS", self.activation.env_value()),
+            ("ZECKIT_FAUCET_MNEMONIC", self.faucet.mnemonic.clone().unwrap_or_default()),
+            ("ZECKIT_FAUCET_PASSPHRASE", self.faucet.passphrase.clone().unwrap_or_default()),
+            ("ZECKIT_FAUCET_BIRTHDAY", self.faucet.birthday.to_string()),
+            ("ZECKIT_FAUCET_ACCOUNTS", self.faucet.accounts.to_string()),
+        ];
+        if let Some(project) = self.compose_project() {
+            env.push(("COMPOSE_PROJECT_NAME", project));
+        }
+        env
+    }
+}
+
+impl Faucet {
+    /// Read `mnemonic_file` into `mnemonic`, unless a mnemonic was given directly.
+    /// Relative paths are taken from the project root.
+    fn resolve_mnemonic(&mut self) -> Result<()> {
+        if self.mnemonic.is_some() {
+            return Ok(());
+        }
+        let Some(path) = &self.mnemonic_file else {
+            return Ok(());
+        };
+
+        let path = if path.is_relative() {
+            crate::utils::project_dir()?.join(path)
+        } else {
+            path.clone()
+        };
+        let phrase = fs::read_to_string(&path)
+            .map_err(|e| ZecKitError::Config(format!("Could not read mnemonic file {:?}: {}", path, e)))?;
+        self.mnemonic = Some(phrase.trim().to_string()).filter(|p| !p.is_empty());
+        Ok(())
+    }
+}
+
+fn override_from_env<T: std::str::FromStr>(name: &str, target: &mut T) -> Result<()> {
+    if let Ok(value) = std::env::var(name) {
+        *target = value
+            .parse()
+            .map_err(|_| ZecKitError::Config(format!("Invalid value for {}: {}", name, value)))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_file_keeps_defaults() {
+        let settings = Settings::from_toml(
+            r#"
+            backend = "zaino"
+
+            [ports]
+            faucet = 18080
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.backend, Backend::Zaino);
+        assert_eq!(settings.ports.faucet, 18080);
+        assert_eq!(settings.ports.zebra_rpc, 8232);
+        assert_eq!(settings.mining.initial_blocks, 101);
+        assert_eq!(settings.faucet_api_url(), "http://127.0.0.1:18080");
+        assert_eq!(settings.activation.nu5, Some(1));
+        assert_eq!(settings.activation.nu6, None);
+    }
+
+    #[test]
+    fn activation_heights_from_file() {
+        let settings = Settings::from_toml("[activation]\nnu6 = 150\n\"nu6.1\" = 200\n").unwrap();
+        assert_eq!(settings.activation.nu6, Some(150));
+        assert_eq!(settings.activation.nu6_1, Some(200));
+        assert!(Settings::from_toml("[activation]\nnu9 = 1\n").is_err());
+    }
+
+    #[test]
+    fn host_is_published_and_used_in_urls() {
+        let settings = Settings::from_toml("host = \"::1\"").unwrap();
+        assert_eq!(settings.zebra_rpc_url(), "http://[::1]:8232");
+        assert!(settings.compose_env().contains(&("ZECKIT_HOST", "[::1]".to_string())));
+
+        let settings = Settings::from_toml("host = \"0.0.0.0\"").unwrap();
+        assert!(settings.compose_env().contains(&("ZECKIT_HOST", "0.0.0.0".to_string())));
+    }
+
+    #[test]
+    fn each_devnet_gets_its_own_zebra_config_and_fixtures() {
+        let default = Settings::default();
+        let named = Settings::from_toml("name = \"feature-x\"").unwrap();
+        let mounted = |settings: &Settings| {
+            settings
+                .compose_env()
+                .into_iter()
+                .find(|(key, _)| *key == "ZECKIT_ZEBRA_CONFIG")
+                .map(|(_, value)| value)
+        };
+
+        assert_eq!(mounted(&default).as_deref(), Some("./.zeckit/devnets/zeckit/zebra.toml"));
+        assert_eq!(mounted(&named).as_deref(), Some("./.zeckit/devnets/zeckit-feature-x/zebra.toml"));
+        assert_eq!(default.fixtures_dir(), Path::new("fixtures"));
+        assert_eq!(named.fixtures_dir(), Path::new(".zeckit/devnets/zeckit-feature-x/fixtures"));
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        assert!(Settings::from_toml("host = \"localhost\"").is_err());
+        assert!(Settings::from_toml("[ports]\nzebra_rpc = \"eighty\"").is_err());
+        assert!(Settings::from_toml("backend = \"zcashd\"").is_err());
+    }
+
+    #[test]
+    fn faucet_mnemonic_file_is_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("faucet.mnemonic");
+        fs::write(&path, "  word list\n").unwrap();
+
+        let mut settings = Settings::from_toml(&format!(
+            "[faucet]\nmnemonic_file = {:?}\nbirthday = 120\n",
+            path
+        ))
+        .unwrap();
+        settings.faucet.resolve_mnemonic().unwrap();
+
+        assert_eq!(settings.faucet.mnemonic.as_deref(), Some("word list"));
+        assert_eq!(settings.faucet.birthday, 120);
+        assert_eq!(settings.faucet.accounts, 1);
+    }
+}
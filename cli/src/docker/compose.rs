@@ -1,32 +1,93 @@
+use crate::config::Settings;
 use crate::error::{Result, ZecKitError};
-use std::process::{Command, Stdio};
+use serde::{Deserialize, Serialize};
+use std::process::{Child, Command, Stdio};
+
+/// Profiles defined in docker-compose.yml
+pub const PROFILES: &[&str] = &["lwd", "zaino"];
+
+/// Named volumes defined in docker-compose.yml
+pub const VOLUMES: &[&str] = &[
+    "zebra-data",
+    "lightwalletd-data",
+    "zaino-data",
+    "faucet-data",
+    "miner-data",
+];
+
+/// One container as reported by `docker compose ps --format json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainerState {
+    #[serde(rename(deserialize = "Name"))]
+    pub name: String,
+    #[serde(rename(deserialize = "Service"))]
+    pub service: String,
+    #[serde(rename(deserialize = "State"))]
+    pub state: String,
+    #[serde(rename(deserialize = "Health"), default)]
+    pub health: String,
+    #[serde(rename(deserialize = "Status"), default)]
+    pub status: String,
+}
+
+impl ContainerState {
+    pub fn is_running(&self) -> bool {
+        self.state == "running"
+    }
+}
+
+/// Options for streaming `docker compose logs`
+#[derive(Debug, Clone, Default)]
+pub struct LogOptions {
+    pub tail: Option<usize>,
+    pub follow: bool,
+    pub since: Option<String>,
+}
 
 #[derive(Clone)]
 pub struct DockerCompose {
     project_dir: String,
+    env: Vec<(&'static str, String)>,
+    offline: bool,
 }
 
 impl DockerCompose {
-    pub fn new() -> Result<Self> {
-        // Get project root (go up from cli/ directory)
-        let current_dir = std::env::current_dir()?;
-        let project_dir = if current_dir.ends_with("cli") {
-            current_dir.parent().unwrap().to_path_buf()
-        } else {
-            current_dir
-        };
+    pub fn new(settings: &Settings) -> Result<Self> {
+        let project_dir = crate::utils::project_dir()?;
 
         Ok(Self {
             project_dir: project_dir.to_string_lossy().to_string(),
+            env: settings.compose_env(),
+            offline: false,
         })
     }
 
-    pub fn up(&self, services: &[&str]) -> Result<()> {
+    /// Never build or pull images when starting services (`zeckit up --offline`)
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Run against the docker-compose.yml in `dir` instead of the current project
+    pub fn in_dir(mut self, dir: &std::path::Path) -> Self {
+        self.project_dir = dir.to_string_lossy().to_string();
+        self
+    }
+
+    /// `docker compose` in the project directory, with the configured ports exported
+    fn command(&self) -> Command {
         let mut cmd = Command::new("docker");
         cmd.arg("compose")
-            .arg("up")
-            .arg("-d")
-            .current_dir(&self.project_dir);
+            .current_dir(&self.project_dir)
+            .envs(self.env.iter().map(|(k, v)| (*k, v.as_str())));
+        cmd
+    }
+
+    pub fn up(&self, services: &[&str]) -> Result<()> {
+        let mut cmd = self.command();
+        cmd.arg("up")
+            .arg("-d");
+        self.offline_args(&mut cmd);
 
         for service in services {
             cmd.arg(service);
@@ -42,59 +103,139 @@ impl DockerCompose {
         Ok(())
     }
 
+    /// Services docker-compose.yml defines when `profile` (if any) is active
+    pub fn services(&self, profile: Option<&str>) -> Result<Vec<String>> {
+        let mut cmd = self.command();
+        if let Some(profile) = profile {
+            cmd.arg("--profile").arg(profile);
+        }
+
+        let output = cmd.arg("config").arg("--services").output()?;
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(ZecKitError::Docker(error.to_string()));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect())
+    }
+
+    /// Images used when `profile` (if any) is active, and whether each exists locally
+    pub fn images(&self, profile: Option<&str>) -> Result<Vec<(String, bool)>> {
+        let mut cmd = self.command();
+        if let Some(profile) = profile {
+            cmd.arg("--profile").arg(profile);
+        }
+
+        let output = cmd.arg("config").arg("--images").output()?;
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(ZecKitError::Docker(error.to_string()));
+        }
+
+        let mut images: Vec<String> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect();
+        images.sort();
+        images.dedup();
+
+        Ok(images
+            .into_iter()
+            .map(|image| {
+                let exists = Command::new("docker")
+                    .arg("image")
+                    .arg("inspect")
+                    .arg(&image)
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .status()
+                    .map(|s| s.success())
+                    .unwrap_or(false);
+                (image, exists)
+            })
+            .collect())
+    }
+
     /// Check if Docker images exist for a profile
     pub fn images_exist(&self, profile: &str) -> bool {
-        // Get list of images that would be used by this profile
-        let output = Command::new("docker")
-            .arg("compose")
-            .arg("--profile")
-            .arg(profile)
-            .arg("config")
-            .arg("--images")
-            .current_dir(&self.project_dir)
-            .output();
-        
-        match output {
-            Ok(out) if out.status.success() => {
-                let images = String::from_utf8_lossy(&out.stdout);
-                
-                // Check each image exists locally
-                for image in images.lines() {
-                    let check = Command::new("docker")
-                        .arg("image")
-                        .arg("inspect")
-                        .arg(image.trim())
-                        .stdout(Stdio::null())
-                        .stderr(Stdio::null())
-                        .status();
-                    
-                    if check.map(|s| !s.success()).unwrap_or(true) {
-                        return false; // At least one image missing
-                    }
-                }
-                
-                true // All images exist
-            }
-            _ => false
+        self.images(Some(profile))
+            .map(|images| images.iter().all(|(_, exists)| *exists))
+            .unwrap_or(false)
+    }
+
+    /// Fail unless every image for `profile` (if any) exists locally, for
+    /// `zeckit up --offline` which must not build or pull
+    pub fn require_images(&self, profile: Option<&str>) -> Result<()> {
+        let missing: Vec<String> = self
+            .images(profile)?
+            .into_iter()
+            .filter(|(_, exists)| !exists)
+            .map(|(image, _)| image)
+            .collect();
+
+        if missing.is_empty() {
+            return Ok(());
+        }
+        Err(ZecKitError::Docker(format!(
+            "Offline mode: image(s) not available locally: {} (load a bundle with: zeckit images load <file>)",
+            missing.join(", ")
+        )))
+    }
+
+    /// Image name of each service defined when `profile` (if any) is active,
+    /// from the `image:` key every service in docker-compose.yml pins
+    pub fn service_images(&self, profile: Option<&str>) -> Result<Vec<(String, String)>> {
+        let mut cmd = self.command();
+        if let Some(profile) = profile {
+            cmd.arg("--profile").arg(profile);
         }
+
+        let output = cmd.arg("config").arg("--format").arg("json").output()?;
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(ZecKitError::Docker(error.to_string()));
+        }
+
+        let config: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        let services = config
+            .get("services")
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| ZecKitError::Docker("docker compose config has no services".into()))?;
+
+        services
+            .iter()
+            .map(|(service, definition)| {
+                let image = definition.get("image").and_then(|v| v.as_str()).ok_or_else(|| {
+                    ZecKitError::Docker(format!("Service {} in docker-compose.yml has no image: name", service))
+                })?;
+                Ok((service.clone(), image.to_string()))
+            })
+            .collect()
     }
 
     /// Start services with profile, building only if needed
     pub fn up_with_profile(&self, profile: &str, force_build: bool) -> Result<()> {
-        let needs_build = force_build || !self.images_exist(profile);
+        let needs_build = !self.offline && (force_build || !self.images_exist(profile));
         
         if needs_build {
-            println!("Building Docker images for profile '{}'...", profile);
-            println!("(This may take 10-20 minutes on first build)");
-            println!();
+            say!("Building Docker images for profile '{}'...", profile);
+            say!("(This may take 10-20 minutes on first build)");
+            say!();
             
             // Build with LIVE output instead of silent
-            let build_status = Command::new("docker")
-                .arg("compose")
+            let build_status = self.command()
                 .arg("--profile")
                 .arg(profile)
                 .arg("build")
-                .current_dir(&self.project_dir)
+                .stdout(crate::output::child_stdout())
                 .status()  // This shows output in real-time!
                 .map_err(|e| ZecKitError::Docker(format!("Failed to start build: {}", e)))?;
 
@@ -102,19 +243,19 @@ impl DockerCompose {
                 return Err(ZecKitError::Docker("Image build failed".into()));
             }
 
-            println!("✓ Images built successfully");
-            println!();
+            say!("✓ Images built successfully");
+            say!();
         }
 
         // Start services with live output
-        println!("Starting containers...");
-        Command::new("docker")
-            .arg("compose")
-            .arg("--profile")
+        say!("Starting containers...");
+        let mut cmd = self.command();
+        cmd.arg("--profile")
             .arg(profile)
             .arg("up")
-            .arg("-d")
-            .current_dir(&self.project_dir)
+            .arg("-d");
+        self.offline_args(&mut cmd);
+        cmd.stdout(crate::output::child_stdout())
             .status()?
             .success()
             .then_some(())
@@ -123,11 +264,59 @@ impl DockerCompose {
         Ok(())
     }
 
+    /// Make `up` fail on a missing image instead of building or pulling it
+    fn offline_args(&self, cmd: &mut Command) {
+        if self.offline {
+            cmd.arg("--no-build").arg("--pull").arg("never");
+        }
+    }
+
+    /// Start a single service without touching its dependencies
+    pub fn start_service(&self, service: &str) -> Result<()> {
+        let mut cmd = self.command();
+        cmd.arg("up")
+            .arg("-d")
+            .arg("--no-deps");
+        self.offline_args(&mut cmd);
+        let output = cmd.arg(service).output()?;
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(ZecKitError::Docker(error.to_string()));
+        }
+
+        Ok(())
+    }
+
+    pub fn stop_service(&self, service: &str) -> Result<()> {
+        let output = self.command()
+            .arg("stop")
+            .arg(service)
+            .output()?;
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(ZecKitError::Docker(error.to_string()));
+        }
+
+        Ok(())
+    }
+
+    pub fn is_service_running(&self, service: &str) -> bool {
+        self.command()
+            .arg("ps")
+            .arg("--status")
+            .arg("running")
+            .arg("-q")
+            .arg(service)
+            .output()
+            .map(|output| output.status.success() && !output.stdout.is_empty())
+            .unwrap_or(false)
+    }
+
     pub fn down(&self, volumes: bool) -> Result<()> {
-        let mut cmd = Command::new("docker");
-        cmd.arg("compose")
-            .arg("down")
-            .current_dir(&self.project_dir);
+        let mut cmd = self.command();
+        cmd.arg("down");
 
         if volumes {
             cmd.arg("-v");
@@ -143,13 +332,51 @@ impl DockerCompose {
         Ok(())
     }
 
-    pub fn ps(&self) -> Result<Vec<String>> {
-        let output = Command::new("docker")
-            .arg("compose")
+    /// Stop every container in every profile, keeping containers and volumes
+    pub fn stop(&self) -> Result<()> {
+        let mut cmd = self.command();
+        for profile in PROFILES {
+            cmd.arg("--profile").arg(profile);
+        }
+
+        let output = cmd.arg("stop").output()?;
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(ZecKitError::Docker(error.to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Compose project name, which prefixes container-independent resources such as volumes
+    pub fn project_name(&self) -> Result<String> {
+        let output = self.command()
+            .arg("config")
+            .arg("--format")
+            .arg("json")
+            .output()?;
+
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(ZecKitError::Docker(error.to_string()));
+        }
+
+        let config: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        config
+            .get("name")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| ZecKitError::Docker("docker compose config has no project name".into()))
+    }
+
+    /// Containers of this project, from `docker compose ps --format json`
+    pub fn ps(&self) -> Result<Vec<ContainerState>> {
+        let output = self.command()
             .arg("ps")
+            .arg("--all")
             .arg("--format")
-            .arg("table")
-            .current_dir(&self.project_dir)
+            .arg("json")
             .output()?;
 
         if !output.status.success() {
@@ -158,24 +385,26 @@ impl DockerCompose {
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let lines: Vec<String> = stdout
-            .lines()
-            .skip(1) // Skip header
-            .map(|l| l.to_string())
-            .collect();
+        let trimmed = stdout.trim();
 
-        Ok(lines)
+        // Older compose versions print one JSON array, newer ones one object per line
+        if trimmed.starts_with('[') {
+            return Ok(serde_json::from_str(trimmed)?);
+        }
+
+        trimmed
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| serde_json::from_str(l).map_err(ZecKitError::from))
+            .collect()
     }
 
-    #[allow(dead_code)]
     pub fn logs(&self, service: &str, tail: usize) -> Result<Vec<String>> {
-        let output = Command::new("docker")
-            .arg("compose")
+        let output = self.command()
             .arg("logs")
             .arg("--tail")
             .arg(tail.to_string())
             .arg(service)
-            .current_dir(&self.project_dir)
             .output()?;
 
         if !output.status.success() {
@@ -189,14 +418,40 @@ impl DockerCompose {
         Ok(lines)
     }
 
-    #[allow(dead_code)]
+    /// Spawn `docker compose logs` with stdout piped, across every profile so
+    /// backend- and faucet-specific services are visible. Services without a
+    /// container simply produce no output.
+    pub fn logs_stream(&self, services: &[&str], options: &LogOptions) -> Result<Child> {
+        let mut cmd = self.command();
+        for profile in PROFILES {
+            cmd.arg("--profile").arg(profile);
+        }
+        cmd.arg("logs")
+            .arg("--no-color")
+            .arg("--timestamps");
+
+        if let Some(tail) = options.tail {
+            cmd.arg("--tail").arg(tail.to_string());
+        }
+        if options.follow {
+            cmd.arg("--follow");
+        }
+        if let Some(since) = &options.since {
+            cmd.arg("--since").arg(since);
+        }
+
+        cmd.args(services)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|e| ZecKitError::Docker(format!("Failed to run docker compose logs: {}", e)))
+    }
+
     pub fn exec(&self, service: &str, command: &[&str]) -> Result<String> {
-        let mut cmd = Command::new("docker");
-        cmd.arg("compose")
-            .arg("exec")
+        let mut cmd = self.command();
+        cmd.arg("exec")
             .arg("-T") // Non-interactive
-            .arg(service)
-            .current_dir(&self.project_dir);
+            .arg(service);
 
         for arg in command {
             cmd.arg(arg);
@@ -212,13 +467,10 @@ impl DockerCompose {
         Ok(String::from_utf8_lossy(&output.stdout).to_string())
     }
 
-    #[allow(dead_code)]
     pub fn is_running(&self) -> bool {
-        Command::new("docker")
-            .arg("compose")
+        self.command()
             .arg("ps")
             .arg("-q")
-            .current_dir(&self.project_dir)
             .output()
             .map(|output| !output.stdout.is_empty())
             .unwrap_or(false)
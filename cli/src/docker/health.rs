@@ -1,102 +1,76 @@
+use crate::config::{Backend, Settings};
 use crate::error::{Result, ZecKitError};
+use crate::rpc::indexer::LightdInfo;
+use crate::rpc::{IndexerClient, ZebraRpc};
+use crate::wait;
 use reqwest::Client;
 use indicatif::ProgressBar;
-use tokio::time::{sleep, Duration};
+use tokio::time::Duration;
 use serde_json::Value;
-use std::net::TcpStream;
-use std::time::Duration as StdDuration;
 
 pub struct HealthChecker {
     client: Client,
-    max_retries: u32,
-    retry_delay: Duration,
-    backend_max_retries: u32,
+    zebra: ZebraRpc,
+    faucet_api_url: String,
+    indexer: IndexerClient,
+    request_timeout: Duration,
+    zebra_timeout: Duration,
+    faucet_timeout: Duration,
+    backend_timeout: Duration,
 }
 
 impl HealthChecker {
-    pub fn new() -> Self {
+    pub fn new(settings: &Settings) -> Self {
         Self {
             client: Client::new(),
-            max_retries: 560,
-            retry_delay: Duration::from_secs(2),
-            backend_max_retries: 900,  // CHANGED: Increased from 600 to 900 (30 minutes)
+            zebra: ZebraRpc::new(settings),
+            faucet_api_url: settings.faucet_api_url(),
+            indexer: IndexerClient::new(settings),
+            request_timeout: Duration::from_secs(settings.timeouts.request_secs),
+            zebra_timeout: Duration::from_secs(settings.timeouts.zebra_secs),
+            faucet_timeout: Duration::from_secs(settings.timeouts.faucet_secs),
+            backend_timeout: Duration::from_secs(settings.timeouts.backend_secs),
         }
     }
 
     pub async fn wait_for_zebra(&self, pb: &ProgressBar) -> Result<()> {
-        for i in 0..self.max_retries {
+        wait::until("Zebra", self.zebra_timeout, || async {
             pb.tick();
-            
-            match self.check_zebra().await {
-                Ok(_) => return Ok(()),
-                Err(_) if i < self.max_retries - 1 => {
-                    sleep(self.retry_delay).await;
-                }
-                Err(e) => return Err(e),
-            }
-        }
-
-        Err(ZecKitError::ServiceNotReady("Zebra".into()))
+            self.check_zebra().await.map(Some)
+        })
+        .await
     }
 
     pub async fn wait_for_faucet(&self, pb: &ProgressBar) -> Result<()> {
-        for i in 0..self.max_retries {
+        wait::until("Faucet", self.faucet_timeout, || async {
             pb.tick();
-            
-            match self.check_faucet().await {
-                Ok(_) => return Ok(()),
-                Err(_) if i < self.max_retries - 1 => {
-                    sleep(self.retry_delay).await;
-                }
-                Err(e) => return Err(e),
-            }
-        }
-
-        Err(ZecKitError::ServiceNotReady("Faucet".into()))
+            self.check_faucet().await.map(Some)
+        })
+        .await
     }
 
-    pub async fn wait_for_backend(&self, backend: &str, pb: &ProgressBar) -> Result<()> {
-        for i in 0..self.backend_max_retries {
+    /// Wait for the indexer to catch up with Zebra; returns what it reports about itself
+    pub async fn wait_for_backend(&self, backend: Backend, pb: &ProgressBar) -> Result<LightdInfo> {
+        wait::until(backend.display_name(), self.backend_timeout, || async {
             pb.tick();
-            
-            match self.check_backend(backend).await {
-                Ok(_) => return Ok(()),
-                Err(_) if i < self.backend_max_retries - 1 => {
-                    sleep(self.retry_delay).await;
-                }
-                Err(e) => return Err(e),
-            }
-        }
-
-        Err(ZecKitError::ServiceNotReady(format!("{} not ready", backend)))
+            self.check_backend(backend).await.map(Some)
+        })
+        .await
     }
 
     async fn check_zebra(&self) -> Result<()> {
-        let resp = self
-            .client
-            .post("http://127.0.0.1:8232")
-            .json(&serde_json::json!({
-                "jsonrpc": "2.0",
-                "id": "health",
-                "method": "getblockcount",
-                "params": []
-            }))
-            .timeout(Duration::from_secs(5))
-            .send()
-            .await?;
-
-        if resp.status().is_success() {
-            Ok(())
-        } else {
-            Err(ZecKitError::HealthCheck("Zebra not ready".into()))
-        }
+        self.zebra
+            .get_block_count()
+            .await
+            .map(|_| ())
+            .map_err(|e| ZecKitError::HealthCheck(format!("Zebra not ready: {}", e)))
     }
 
     async fn check_faucet(&self) -> Result<()> {
         let resp = self
             .client
-            .get("http://127.0.0.1:8080/health")
-            .timeout(Duration::from_secs(5))
+            .get(format!("{}/health", self.faucet_api_url))
+            .timeout(self.request_timeout)
             .send()
             .await?;
 
@@ -113,28 +87,25 @@ impl HealthChecker {
         Ok(())
     }
     
-    async fn check_backend(&self, backend: &str) -> Result<()> {
-        // Zaino and Lightwalletd are gRPC services on port 9067
-        // They don't respond to HTTP, so we do a TCP connection check
-        
-        let backend_name = if backend == "lwd" { "lightwalletd" } else { "zaino" };
-        
-        // Try to connect to localhost:9067 with 2 second timeout
-        match TcpStream::connect_timeout(
-            &"127.0.0.1:9067".parse().unwrap(),
-            StdDuration::from_secs(2)
-        ) {
-            Ok(_) => {
-                // For Zaino, give it extra time after port opens to initialize
-                if backend == "zaino" {
-                    sleep(Duration::from_secs(10)).await;
-                }
-                Ok(())
-            }
-            Err(_) => {
-                // Port not accepting connections yet
-                Err(ZecKitError::HealthCheck(format!("{} not ready", backend_name)))
-            }
+    /// Ready once the indexer answers `GetLightdInfo` and its latest block has
+    /// caught up with Zebra's tip
+    async fn check_backend(&self, backend: Backend) -> Result<LightdInfo> {
+        if backend.service().is_none() {
+            return Err(ZecKitError::Config("Backend 'none' has no indexer to check".into()));
         }
+
+        let info = self.indexer.lightd_info().await?;
+        let indexed = self.indexer.latest_block().await?.height;
+        let tip = self.zebra.get_block_count().await?;
+
+        if indexed < tip {
+            return Err(ZecKitError::HealthCheck(format!(
+                "{} at height {} of Zebra's {}",
+                backend.display_name(),
+                indexed,
+                tip
+            )));
+        }
+        Ok(info)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,63 @@
+use crate::error::{Result, ZecKitError};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::Command;
+
+/// OCI label images may carry their upstream version in
+const VERSION_LABEL: &str = "org.opencontainers.image.version";
+
+/// Identity of a local image, as recorded in image bundles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageInfo {
+    /// Content digest of the image config (`sha256:...`); survives save and load
+    pub id: String,
+    pub created: String,
+    pub size_bytes: u64,
+    /// `org.opencontainers.image.version` label, if the image has one
+    pub version: Option<String>,
+}
+
+/// Metadata of a local image, or `None` if it doesn't exist
+pub fn inspect(image: &str) -> Option<ImageInfo> {
+    let output = Command::new("docker").arg("image").arg("inspect").arg(image).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let inspected: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+    let image = inspected.get(0)?;
+    Some(ImageInfo {
+        id: image.get("Id")?.as_str()?.to_string(),
+        created: image.get("Created").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+        size_bytes: image.get("Size").and_then(|v| v.as_u64()).unwrap_or(0),
+        version: image
+            .pointer("/Config/Labels")
+            .and_then(|labels| labels.get(VERSION_LABEL))
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+    })
+}
+
+/// Write `images` to a `docker save` tar at `path`
+pub fn save(images: &[String], path: &Path) -> Result<()> {
+    let output = Command::new("docker").arg("save").arg("-o").arg(path).args(images).output()?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(ZecKitError::Docker(format!("docker save: {}", error.trim())));
+    }
+
+    Ok(())
+}
+
+/// Import a `docker save` tar
+pub fn load(path: &Path) -> Result<()> {
+    let output = Command::new("docker").arg("load").arg("-i").arg(path).output()?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(ZecKitError::Docker(format!("docker load: {}", error.trim())));
+    }
+
+    Ok(())
+}
@@ -1,2 +1,4 @@
 pub mod compose;
-pub mod health;
\ No newline at end of file
+pub mod health;
+pub mod image;
+pub mod volume;
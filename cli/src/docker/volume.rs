@@ -0,0 +1,131 @@
+use crate::error::{Result, ZecKitError};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Image used for throwaway containers that read and write volume contents.
+/// The miner is built from it, so it is already present after the first `zeckit up`.
+pub const HELPER_IMAGE: &str = "debian:bookworm-slim";
+
+/// Docker name of a volume declared in docker-compose.yml
+pub fn compose_name(project: &str, key: &str) -> String {
+    format!("{}_{}", project, key)
+}
+
+pub fn exists(volume: &str) -> bool {
+    Command::new("docker")
+        .arg("volume")
+        .arg("inspect")
+        .arg(volume)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
+/// Create `<project>_<key>` with the labels docker compose expects, so a later
+/// `docker compose up` adopts it instead of warning about a foreign volume
+pub fn create_for_compose(project: &str, key: &str) -> Result<String> {
+    let volume = compose_name(project, key);
+
+    let output = Command::new("docker")
+        .arg("volume")
+        .arg("create")
+        .arg("--label")
+        .arg(format!("com.docker.compose.project={}", project))
+        .arg("--label")
+        .arg(format!("com.docker.compose.volume={}", key))
+        .arg(&volume)
+        .output()?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(ZecKitError::Docker(format!("Volume {}: {}", volume, error.trim())));
+    }
+
+    Ok(volume)
+}
+
+/// Write the contents of `volume` to `<dir>/<file>` as an uncompressed tar
+pub fn export(volume: &str, dir: &Path, file: &str) -> Result<()> {
+    run_helper(volume, dir, &format!("tar cf /backup/{} -C /volume .", file))
+}
+
+/// Replace the contents of `volume` with the tar at `<dir>/<file>`
+pub fn import(volume: &str, dir: &Path, file: &str) -> Result<()> {
+    run_helper(
+        volume,
+        dir,
+        &format!("find /volume -mindepth 1 -delete && tar xf /backup/{} -C /volume", file),
+    )
+}
+
+/// Contents of `path` inside `volume`, or `None` if the file does not exist
+pub fn read_file(volume: &str, path: &str) -> Result<Option<String>> {
+    let output = Command::new("docker")
+        .arg("run")
+        .arg("--rm")
+        .arg("-v")
+        .arg(format!("{}:/volume:ro", volume))
+        .arg(HELPER_IMAGE)
+        .arg("sh")
+        .arg("-c")
+        .arg(format!("test -f /volume/{0} || exit 3; cat /volume/{0}", path))
+        .output()?;
+
+    match output.status.code() {
+        Some(0) => Ok(Some(String::from_utf8_lossy(&output.stdout).to_string())),
+        Some(3) => Ok(None),
+        _ => {
+            let error = String::from_utf8_lossy(&output.stderr);
+            Err(ZecKitError::Docker(format!("Volume {}: {}", volume, error.trim())))
+        }
+    }
+}
+
+/// Contents of `path` inside the running `container` (e.g. a file on a volume
+/// it mounts), or `None` if the file does not exist
+pub fn read_container_file(container: &str, path: &str) -> Result<Option<String>> {
+    let output = Command::new("docker")
+        .arg("exec")
+        .arg(container)
+        .arg("sh")
+        .arg("-c")
+        .arg(format!("test -f {0} || exit 3; cat {0}", path))
+        .output()?;
+
+    match output.status.code() {
+        Some(0) => Ok(Some(String::from_utf8_lossy(&output.stdout).to_string())),
+        Some(3) => Ok(None),
+        _ => {
+            let error = String::from_utf8_lossy(&output.stderr);
+            Err(ZecKitError::Docker(format!("Container {}: {}", container, error.trim())))
+        }
+    }
+}
+
+fn run_helper(volume: &str, dir: &Path, script: &str) -> Result<()> {
+    let dir = dir
+        .canonicalize()
+        .map_err(|e| ZecKitError::Docker(format!("Invalid directory {:?}: {}", dir, e)))?;
+
+    let output = Command::new("docker")
+        .arg("run")
+        .arg("--rm")
+        .arg("-v")
+        .arg(format!("{}:/volume", volume))
+        .arg("-v")
+        .arg(format!("{}:/backup", dir.display()))
+        .arg(HELPER_IMAGE)
+        .arg("sh")
+        .arg("-c")
+        .arg(script)
+        .output()?;
+
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(ZecKitError::Docker(format!("Volume {}: {}", volume, error.trim())));
+    }
+
+    Ok(())
+}
@@ -13,6 +13,27 @@ pub enum ZecKitError {
     #[error("Service not ready: {0}")]
     ServiceNotReady(String),
     
+    #[error("RPC error {code}: {message}")]
+    Rpc { code: i64, message: String },
+    
+    #[error("gRPC error: {0}")]
+    Grpc(String),
+    
+    #[error("Faucet error: {0}")]
+    Faucet(String),
+    
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+    
+    #[error("Snapshot error: {0}")]
+    Snapshot(String),
+    
+    #[error("Preflight failed: {0}")]
+    Preflight(String),
+    
+    #[error("Could not decode {0}")]
+    Decode(String),
+    
     #[error("Configuration error: {0}")]
     Config(String),
     
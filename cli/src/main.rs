@@ -1,18 +1,48 @@
 use clap::{Parser, Subcommand};
 use colored::*;
+use std::path::PathBuf;
 use std::process;
 
+#[macro_use]
+mod output;
+
+mod address;
+mod assets;
 mod commands;
 mod docker;
 mod config;
 mod error;
+mod rpc;
+mod seed;
+mod tx;
 mod utils;
+mod wait;
 
 #[derive(Parser)]
 #[command(name = "zeckit")]
 #[command(about = "ZecKit - Developer toolkit for Zcash on Zebra", long_about = None)]
 #[command(version)]
 struct Cli {
+    /// Path to zeckit.toml (defaults to the project root)
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Output format: text or json (one JSON object on stdout)
+    #[arg(long, global = true, value_enum, default_value_t = output::OutputFormat::Text)]
+    output: output::OutputFormat,
+
+    /// Only print results and errors
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
+    /// Disable coloured output
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Devnet to act on; named devnets get their own containers, volumes and ports
+    #[arg(long, global = true, value_name = "DEVNET")]
+    name: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -21,13 +51,39 @@ struct Cli {
 enum Commands {
     /// Start the ZecKit devnet
     Up {
-        /// Light-client backend: lwd (lightwalletd) or zaino
-        #[arg(short, long, default_value = "none")]
-        backend: String,
+        /// Light-client backend: lwd (lightwalletd), zaino or none [default: from zeckit.toml]
+        #[arg(short, long)]
+        backend: Option<config::Backend>,
         
         /// Force fresh start (remove volumes)
         #[arg(short, long)]
         fresh: bool,
+        
+        /// Re-run maturity mining, fixtures and shielding on an already bootstrapped devnet
+        #[arg(long)]
+        rebootstrap: bool,
+        
+        /// Activate a network upgrade at a height, e.g. nu6=150 (or nu7=off); repeatable
+        #[arg(long = "activate", value_name = "UPGRADE=HEIGHT")]
+        activate: Vec<String>,
+        
+        /// Require Zebra's RPC cookie for JSON-RPC calls [default: from zeckit.toml]
+        #[arg(long)]
+        rpc_auth: bool,
+        
+        /// Never build images; start only from images already present (see `zeckit images load`)
+        #[arg(long)]
+        offline: bool,
+    },
+    
+    /// Write docker-compose.yml, service configs, Dockerfiles and zeckit.toml into a project
+    Init {
+        /// Directory to scaffold [default: current directory]
+        dir: Option<PathBuf>,
+        
+        /// Overwrite files that already exist
+        #[arg(short, long)]
+        force: bool,
     },
     
     /// Stop the ZecKit devnet
@@ -40,26 +96,256 @@ enum Commands {
     /// Show devnet status
     Status,
     
+    /// Check Docker, ports, disk space and project files before starting
+    Doctor,
+    
+    /// List devnets and their endpoints
+    List,
+    
     /// Run smoke tests
-    Test,
+    Test {
+        /// Only run tests whose name contains, or whose tags include, this term; repeatable
+        #[arg(long = "filter", value_name = "TERM")]
+        filters: Vec<String>,
+
+        /// Skip tests whose name contains, or whose tags include, this term; repeatable
+        #[arg(long = "skip", value_name = "TERM")]
+        skips: Vec<String>,
+
+        /// Retry a failing test up to this many times
+        #[arg(long, default_value = "0")]
+        retries: u32,
+
+        /// Fail a test attempt that runs longer than this (e.g. 60s, 2m)
+        #[arg(long, value_parser = utils::parse_duration)]
+        timeout: Option<std::time::Duration>,
+
+        /// Directory of declarative *.toml test cases [default: zeckit-tests]
+        #[arg(long, value_name = "DIR")]
+        tests_dir: Option<PathBuf>,
+
+        /// Write a report as <format>=<path> (junit or tap); repeatable
+        #[arg(long = "report", value_name = "FORMAT=PATH")]
+        reports: Vec<commands::test::ReportSpec>,
+    },
+    
+    /// Mine blocks on demand
+    Mine {
+        /// Number of blocks to mine
+        #[arg(required_unless_present = "to_height", conflicts_with = "to_height")]
+        count: Option<u32>,
+        
+        /// Mine until the chain reaches this height
+        #[arg(long)]
+        to_height: Option<u64>,
+        
+        /// Wait for the faucet wallet to sync the new tip
+        #[arg(long)]
+        wait_sync: bool,
+    },
+    
+    /// Call a Zebra JSON-RPC method, e.g. `zeckit rpc getblockhash 1`
+    Rpc {
+        /// RPC method name
+        method: String,
+        
+        /// Parameters; numbers and JSON are parsed, anything else is sent as a string
+        #[arg(allow_hyphen_values = true)]
+        params: Vec<String>,
+    },
+    
+    /// Show a block and a summary of its transactions
+    Block {
+        /// Block height or hash
+        id: String,
+    },
+    
+    /// Decode a transaction: version, branch, transparent ins/outs, shielded counts and fee
+    Tx {
+        /// Transaction id
+        txid: String,
+    },
+    
+    /// Control the background miner
+    Miner {
+        #[command(subcommand)]
+        action: commands::miner::MinerAction,
+    },
+    
+    /// Save or restore the devnet chain, wallet and fixtures
+    Snapshot {
+        #[command(subcommand)]
+        action: commands::snapshot::SnapshotAction,
+    },
+    
+    /// Save or load the devnet images as a bundle for offline use
+    Images {
+        #[command(subcommand)]
+        action: commands::images::ImagesAction,
+    },
+    
+    /// Inspect and operate the faucet wallet
+    Wallet {
+        #[command(subcommand)]
+        action: commands::wallet::WalletAction,
+    },
+    
+    /// Generate faucet wallet seeds
+    Seed {
+        #[command(subcommand)]
+        action: commands::seed::SeedAction,
+    },
+    
+    /// Show service logs
+    Logs {
+        /// Service: zebra, lightwalletd (lwd), zaino, backend, faucet or miner [default: all]
+        service: Option<String>,
+        
+        /// Number of lines to show from the end of each log
+        #[arg(short = 'n', long, default_value_t = 100)]
+        tail: usize,
+        
+        /// Keep streaming new log lines
+        #[arg(short, long)]
+        follow: bool,
+        
+        /// Only show logs newer than this (e.g. 10m, 2h, 2024-01-01T00:00:00)
+        #[arg(long)]
+        since: Option<String>,
+        
+        /// Only show lines matching this regular expression
+        #[arg(short, long)]
+        grep: Option<String>,
+    },
+    
+    /// Request funds from the faucet
+    Fund {
+        /// Regtest address to fund (transparent, Sapling or unified)
+        address: String,
+        
+        /// Amount in ZEC [default: faucet default]
+        #[arg(short, long)]
+        amount: Option<f64>,
+        
+        /// Memo to attach (shielded recipients only)
+        #[arg(short, long)]
+        memo: Option<String>,
+        
+        /// Mine blocks until the transaction has this many confirmations
+        #[arg(short, long, default_value_t = 0)]
+        wait_confirmations: u64,
+    },
 }
 
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
+    output::init(cli.output, cli.quiet, cli.no_color);
+    
+    let mut settings = match config::Settings::load(cli.config.as_deref()) {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".red().bold(), e);
+            process::exit(1);
+        }
+    };
+    
+    if let Some(name) = cli.name {
+        settings.name = Some(name);
+    }
+    if let Commands::Up { backend, activate, rpc_auth, .. } = &cli.command {
+        if let Some(backend) = backend {
+            settings.backend = *backend;
+        }
+        if *rpc_auth {
+            settings.zebra.rpc_auth = true;
+        }
+        for spec in activate {
+            settings.activation.apply(spec).unwrap_or_else(|e| {
+                eprintln!("{} --activate {}: {}", "Error:".red().bold(), spec, e);
+                process::exit(1);
+            });
+        }
+        if let Err(e) = settings.activation.validate() {
+            eprintln!("{} Invalid activation heights: {}", "Error:".red().bold(), e);
+            process::exit(1);
+        }
+    }
+    if !matches!(cli.command, Commands::List | Commands::Seed { .. } | Commands::Init { .. }) {
+        let create = matches!(cli.command, Commands::Up { .. });
+        if let Err(e) = config::devnet::resolve(&mut settings, create) {
+            eprintln!("{} {}", "Error:".red().bold(), e);
+            process::exit(1);
+        }
+    }
     
     let result = match cli.command {
-        Commands::Up { backend, fresh } => {
-            commands::up::execute(backend, fresh).await
+        Commands::Up { fresh, rebootstrap, offline, .. } => {
+            commands::up::execute(&settings, fresh, rebootstrap, offline).await
+        }
+        Commands::Init { dir, force } => {
+            commands::init::execute(dir, force).await
         }
         Commands::Down { purge } => {
-            commands::down::execute(purge).await
+            commands::down::execute(&settings, purge).await
+        }
+        Commands::Doctor => {
+            commands::doctor::execute(&settings).await
         }
         Commands::Status => {
-            commands::status::execute().await
+            commands::status::execute(&settings).await
+        }
+        Commands::List => {
+            commands::list::execute(&settings).await
+        }
+        Commands::Test { filters, skips, retries, timeout, tests_dir, reports } => {
+            let options = commands::test::TestOptions {
+                filters,
+                skips,
+                retries,
+                timeout,
+                tests_dir,
+                reports,
+            };
+            commands::test::execute(&settings, &options).await
+        }
+        Commands::Mine { count, to_height, wait_sync } => {
+            commands::mine::execute(&settings, count, to_height, wait_sync).await
+        }
+        Commands::Rpc { method, params } => {
+            commands::rpc::execute(&settings, &method, &params).await
+        }
+        Commands::Block { id } => {
+            commands::block::execute(&settings, &id).await
+        }
+        Commands::Tx { txid } => {
+            commands::tx::execute(&settings, &txid).await
+        }
+        Commands::Miner { action } => {
+            commands::miner::execute(&settings, action).await
+        }
+        Commands::Snapshot { action } => {
+            commands::snapshot::execute(&settings, action).await
+        }
+        Commands::Images { action } => {
+            commands::images::execute(&settings, action).await
+        }
+        Commands::Wallet { action } => {
+            commands::wallet::execute(&settings, action).await
+        }
+        Commands::Seed { action } => {
+            commands::seed::execute(action).await
+        }
+        Commands::Logs { service, tail, follow, since, grep } => {
+            let options = docker::compose::LogOptions {
+                tail: Some(tail),
+                follow,
+                since,
+            };
+            commands::logs::execute(&settings, service, options, grep).await
         }
-        Commands::Test => {
-            commands::test::execute().await
+        Commands::Fund { address, amount, memo, wait_confirmations } => {
+            commands::fund::execute(&settings, address, amount, memo, wait_confirmations).await
         }
     };
     
@@ -0,0 +1,178 @@
+use crate::error::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Arguments;
+use std::io::{self, Write};
+use std::process::Stdio;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable, colourised output
+    #[default]
+    Text,
+    /// One JSON object on stdout; progress goes to stderr
+    Json,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Mode {
+    format: OutputFormat,
+    quiet: bool,
+}
+
+/// Kind of human-readable line, which decides whether `--quiet` hides it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line {
+    Progress,
+    /// What the command was run to find out. JSON mode reports it through
+    /// [`emit`] instead, so there it is treated like progress.
+    Result,
+}
+
+impl Mode {
+    fn shows(&self, line: Line) -> bool {
+        !self.quiet || (line == Line::Result && self.format == OutputFormat::Text)
+    }
+}
+
+static MODE: OnceLock<Mode> = OnceLock::new();
+
+/// Set the output mode for the whole process. Call once, before any command runs.
+pub fn init(format: OutputFormat, quiet: bool, no_color: bool) {
+    if no_color {
+        colored::control::set_override(false);
+    }
+    let _ = MODE.set(Mode { format, quiet });
+}
+
+fn mode() -> Mode {
+    MODE.get().copied().unwrap_or(Mode {
+        format: OutputFormat::Text,
+        quiet: false,
+    })
+}
+
+pub fn is_json() -> bool {
+    mode().format == OutputFormat::Json
+}
+
+/// Human-readable progress text. Suppressed with `--quiet` and moved to stderr
+/// in JSON mode so stdout stays machine-readable.
+pub fn write(args: Arguments, newline: bool) {
+    write_line(args, newline, Line::Progress)
+}
+
+/// Human-readable result text: like [`write`], but still printed with `--quiet`
+pub fn write_result(args: Arguments) {
+    write_line(args, true, Line::Result)
+}
+
+fn write_line(args: Arguments, newline: bool, line: Line) {
+    let mode = mode();
+    if !mode.shows(line) {
+        return;
+    }
+
+    if mode.format == OutputFormat::Json {
+        let mut err = io::stderr().lock();
+        let _ = err.write_fmt(args);
+        if newline {
+            let _ = writeln!(err);
+        }
+    } else {
+        let mut out = io::stdout().lock();
+        let _ = out.write_fmt(args);
+        if newline {
+            let _ = writeln!(out);
+        }
+        let _ = out.flush();
+    }
+}
+
+/// Where child processes (docker build output etc.) should write, following the same rules
+pub fn child_stdout() -> Stdio {
+    let mode = mode();
+    if mode.quiet {
+        Stdio::null()
+    } else if mode.format == OutputFormat::Json {
+        io::stderr().into()
+    } else {
+        Stdio::inherit()
+    }
+}
+
+/// Print a command's structured result. Only does anything in JSON mode.
+pub fn emit<T: Serialize>(value: &T) -> Result<()> {
+    if is_json() {
+        println!("{}", serde_json::to_string_pretty(value)?);
+    }
+    Ok(())
+}
+
+/// Print one record of a streamed result as a JSON line, for commands that
+/// produce output as they go. Only does anything in JSON mode.
+pub fn emit_line<T: Serialize>(value: &T) -> Result<()> {
+    if is_json() {
+        let mut out = io::stdout().lock();
+        writeln!(out, "{}", serde_json::to_string(value)?)?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+/// `println!` for human-readable output (see [`write`])
+macro_rules! say {
+    () => {
+        $crate::output::write(format_args!(""), true)
+    };
+    ($($arg:tt)*) => {
+        $crate::output::write(format_args!($($arg)*), true)
+    };
+}
+
+/// `println!` for a command's result, kept with `--quiet` (see [`write_result`])
+macro_rules! result {
+    ($($arg:tt)*) => {
+        $crate::output::write_result(format_args!($($arg)*))
+    };
+}
+
+/// `print!` for human-readable output (see [`write`])
+macro_rules! say_inline {
+    ($($arg:tt)*) => {
+        $crate::output::write(format_args!($($arg)*), false)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quiet_text_mode_still_prints_results() {
+        let quiet = Mode {
+            format: OutputFormat::Text,
+            quiet: true,
+        };
+        assert!(quiet.shows(Line::Result));
+        assert!(!quiet.shows(Line::Progress));
+
+        let normal = Mode {
+            format: OutputFormat::Text,
+            quiet: false,
+        };
+        assert!(normal.shows(Line::Result));
+        assert!(normal.shows(Line::Progress));
+    }
+
+    #[test]
+    fn quiet_json_mode_leaves_results_to_emit() {
+        let quiet = Mode {
+            format: OutputFormat::Json,
+            quiet: true,
+        };
+        assert!(!quiet.shows(Line::Result));
+        assert!(!quiet.shows(Line::Progress));
+    }
+}
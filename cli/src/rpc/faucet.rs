@@ -0,0 +1,229 @@
+use crate::config::Settings;
+use crate::error::{Result, ZecKitError};
+use reqwest::{Client, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::time::Duration;
+
+/// HTTP client for the faucet API
+#[derive(Clone)]
+pub struct FaucetApi {
+    client: Client,
+    url: String,
+    timeout: Duration,
+    wallet_timeout: Duration,
+}
+
+/// Response of `POST /request`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FundResponse {
+    pub txid: String,
+    pub address: String,
+    pub amount: f64,
+    pub new_balance: f64,
+}
+
+/// Response of `GET /stats`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaucetStats {
+    pub faucet_address: String,
+    pub current_balance: f64,
+    #[serde(default)]
+    pub transparent_balance: f64,
+    #[serde(default)]
+    pub sapling_balance: f64,
+    #[serde(default)]
+    pub orchard_balance: f64,
+    /// Height the wallet has synced to; absent before its first sync
+    #[serde(default)]
+    pub synced_height: Option<u64>,
+    #[serde(default)]
+    pub total_requests: u64,
+    #[serde(default)]
+    pub total_sent: f64,
+    #[serde(default)]
+    pub uptime_seconds: i64,
+}
+
+/// Response of `GET /address`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletAddresses {
+    pub unified_address: String,
+    pub transparent_address: String,
+}
+
+/// Response of `POST /sync`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncResponse {
+    #[serde(default)]
+    pub synced_height: Option<u64>,
+}
+
+/// Response of `POST /shield`; `txid` is absent when there was nothing to shield
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShieldResponse {
+    pub status: String,
+    #[serde(default)]
+    pub txid: Option<String>,
+    #[serde(default)]
+    pub transparent_amount: f64,
+    #[serde(default)]
+    pub shielded_amount: f64,
+    #[serde(default)]
+    pub fee: f64,
+}
+
+/// Response of `POST /send`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SendResponse {
+    pub txid: String,
+    pub to_address: String,
+    pub amount: f64,
+    #[serde(default)]
+    pub memo: String,
+    pub new_balance: f64,
+    #[serde(default)]
+    pub orchard_balance: f64,
+}
+
+/// One entry of `GET /history`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub timestamp: String,
+    pub to_address: String,
+    pub amount: f64,
+    pub txid: String,
+    #[serde(default)]
+    pub memo: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct HistoryResponse {
+    transactions: Vec<HistoryEntry>,
+}
+
+impl FaucetApi {
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            client: Client::new(),
+            url: settings.faucet_api_url(),
+            timeout: Duration::from_secs(settings.timeouts.request_secs),
+            wallet_timeout: Duration::from_secs(settings.timeouts.wallet_secs),
+        }
+    }
+
+    pub async fn stats(&self) -> Result<FaucetStats> {
+        let resp = self
+            .client
+            .get(format!("{}/stats", self.url))
+            .timeout(self.timeout)
+            .send()
+            .await?;
+
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Ask the faucet to send funds to `address`
+    pub async fn request_funds(
+        &self,
+        address: &str,
+        amount: Option<f64>,
+        memo: Option<&str>,
+    ) -> Result<FundResponse> {
+        let resp = self
+            .client
+            .post(format!("{}/request", self.url))
+            .json(&json!({
+                "address": address,
+                "amount": amount,
+                "memo": memo
+            }))
+            .timeout(self.wallet_timeout)
+            .send()
+            .await?;
+
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    pub async fn addresses(&self) -> Result<WalletAddresses> {
+        let resp = self
+            .client
+            .get(format!("{}/address", self.url))
+            .timeout(self.timeout)
+            .send()
+            .await?;
+
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Sync the faucet wallet with the indexer's tip
+    pub async fn sync(&self) -> Result<SyncResponse> {
+        let resp = self
+            .client
+            .post(format!("{}/sync", self.url))
+            .timeout(self.wallet_timeout)
+            .send()
+            .await?;
+
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Shield transparent funds into the Orchard pool
+    pub async fn shield(&self) -> Result<ShieldResponse> {
+        let resp = self
+            .client
+            .post(format!("{}/shield", self.url))
+            .timeout(self.wallet_timeout)
+            .send()
+            .await?;
+
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Send from the wallet's Orchard pool to `address`
+    pub async fn send(&self, address: &str, amount: f64, memo: Option<&str>) -> Result<SendResponse> {
+        let resp = self
+            .client
+            .post(format!("{}/send", self.url))
+            .json(&json!({
+                "address": address,
+                "amount": amount,
+                "memo": memo
+            }))
+            .timeout(self.wallet_timeout)
+            .send()
+            .await?;
+
+        Ok(check_status(resp).await?.json().await?)
+    }
+
+    /// Most recent faucet payouts, newest first
+    pub async fn history(&self, limit: usize) -> Result<Vec<HistoryEntry>> {
+        let resp = self
+            .client
+            .get(format!("{}/history", self.url))
+            .query(&[("limit", limit)])
+            .timeout(self.timeout)
+            .send()
+            .await?;
+
+        let history: HistoryResponse = check_status(resp).await?.json().await?;
+        Ok(history.transactions)
+    }
+}
+
+/// Turn a non-2xx faucet response into an error carrying its `error` message
+async fn check_status(resp: Response) -> Result<Response> {
+    if resp.status().is_success() {
+        return Ok(resp);
+    }
+
+    let status = resp.status();
+    let body = resp.text().await.unwrap_or_default();
+    let message = serde_json::from_str::<Value>(&body)
+        .ok()
+        .and_then(|v| v.get("error").and_then(|e| e.as_str()).map(str::to_string))
+        .unwrap_or(body);
+
+    Err(ZecKitError::Faucet(format!("{} ({})", message, status)))
+}
@@ -0,0 +1,99 @@
+use crate::config::Settings;
+use crate::error::{Result, ZecKitError};
+use serde::Serialize;
+use tokio::time::Duration;
+use tonic::codec::ProstCodec;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::transport::Endpoint;
+
+/// `CompactTxStreamer` methods, from lightwallet-protocol's service.proto
+const GET_LATEST_BLOCK: &str = "/cash.z.wallet.sdk.rpc.CompactTxStreamer/GetLatestBlock";
+const GET_LIGHTD_INFO: &str = "/cash.z.wallet.sdk.rpc.CompactTxStreamer/GetLightdInfo";
+
+/// `Empty`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Empty {}
+
+/// `ChainSpec` (empty)
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ChainSpec {}
+
+/// `BlockID`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BlockId {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub hash: Vec<u8>,
+}
+
+/// `LightdInfo`, the fields the CLI reports
+#[derive(Clone, PartialEq, prost::Message, Serialize)]
+pub struct LightdInfo {
+    #[prost(string, tag = "1")]
+    pub version: String,
+    #[prost(string, tag = "2")]
+    pub vendor: String,
+    #[prost(string, tag = "4")]
+    pub chain_name: String,
+    #[prost(string, tag = "6")]
+    pub consensus_branch_id: String,
+    #[prost(uint64, tag = "7")]
+    pub block_height: u64,
+}
+
+/// gRPC client for the devnet's light-client indexer (lightwalletd or Zaino)
+#[derive(Clone)]
+pub struct IndexerClient {
+    url: String,
+    timeout: Duration,
+}
+
+impl IndexerClient {
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            url: settings.backend_url(),
+            timeout: Duration::from_secs(settings.timeouts.request_secs),
+        }
+    }
+
+    /// Vendor, version and chain of the indexer (`GetLightdInfo`)
+    pub async fn lightd_info(&self) -> Result<LightdInfo> {
+        self.unary(GET_LIGHTD_INFO, Empty {}).await
+    }
+
+    /// Height and hash of the indexer's latest block (`GetLatestBlock`)
+    pub async fn latest_block(&self) -> Result<BlockId> {
+        self.unary(GET_LATEST_BLOCK, ChainSpec {}).await
+    }
+
+    async fn unary<Req, Resp>(&self, method: &'static str, request: Req) -> Result<Resp>
+    where
+        Req: prost::Message + 'static,
+        Resp: prost::Message + Default + 'static,
+    {
+        let grpc_error = |e: &dyn std::fmt::Display| ZecKitError::Grpc(format!("{} at {}: {}", method, self.url, e));
+
+        let channel = Endpoint::from_shared(self.url.clone())
+            .map_err(|e| grpc_error(&e))?
+            .connect_timeout(self.timeout)
+            .timeout(self.timeout)
+            .connect()
+            .await
+            .map_err(|e| grpc_error(&e))?;
+
+        let mut grpc = tonic::client::Grpc::new(channel);
+        grpc.ready().await.map_err(|e| grpc_error(&e))?;
+
+        let response = grpc
+            .unary(
+                tonic::Request::new(request),
+                PathAndQuery::from_static(method),
+                ProstCodec::default(),
+            )
+            .await
+            .map_err(|status| grpc_error(&status.message()))?;
+
+        Ok(response.into_inner())
+    }
+}
@@ -0,0 +1,7 @@
+pub mod faucet;
+pub mod indexer;
+pub mod zebra;
+
+pub use faucet::FaucetApi;
+pub use indexer::IndexerClient;
+pub use zebra::ZebraRpc;
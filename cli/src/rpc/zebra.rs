@@ -0,0 +1,224 @@
+use crate::config::Settings;
+use crate::docker::volume;
+use crate::error::{Result, ZecKitError};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+use tokio::time::Duration;
+
+/// Fields of `getblockchaininfo` the CLI uses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockchainInfo {
+    pub chain: String,
+    pub blocks: u64,
+    #[serde(rename = "bestblockhash")]
+    pub best_block_hash: String,
+    /// Network upgrades keyed by branch id
+    #[serde(default)]
+    pub upgrades: BTreeMap<String, NetworkUpgrade>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkUpgrade {
+    pub name: String,
+    #[serde(rename = "activationheight")]
+    pub activation_height: u64,
+    pub status: String,
+}
+
+/// `getblock` at verbosity 1
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Block {
+    pub hash: String,
+    pub height: u64,
+    #[serde(default)]
+    pub confirmations: i64,
+    #[serde(default)]
+    pub time: Option<i64>,
+    #[serde(default)]
+    pub size: Option<u64>,
+    #[serde(default, rename = "previousblockhash")]
+    pub previous_block_hash: Option<String>,
+    /// Transaction ids, coinbase first
+    #[serde(default)]
+    pub tx: Vec<String>,
+}
+
+/// `getrawtransaction` at verbosity 1
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawTransaction {
+    pub hex: String,
+    /// Mined height; absent or negative while in the mempool
+    #[serde(default)]
+    pub height: Option<i64>,
+    #[serde(default)]
+    pub confirmations: Option<u64>,
+}
+
+impl RawTransaction {
+    pub fn mined_height(&self) -> Option<u64> {
+        self.height.and_then(|h| u64::try_from(h).ok())
+    }
+}
+
+/// zcashd-compatible error code Zebra returns for an unknown transaction
+const RPC_INVALID_ADDRESS_OR_KEY: i64 = -5;
+
+/// Where Zebra writes its RPC cookie (`[rpc] cookie_dir` in zebra.toml)
+pub const COOKIE_FILE: &str = "/var/zebra-cookie/.cookie";
+
+/// JSON-RPC client for the devnet's Zebra node
+#[derive(Clone)]
+pub struct ZebraRpc {
+    client: Client,
+    url: String,
+    timeout: Duration,
+    cookie_file: Option<PathBuf>,
+    zebra_container: String,
+    /// `user:password` from the cookie, loaded the first time Zebra asks for auth
+    credentials: Arc<OnceCell<(String, String)>>,
+}
+
+impl ZebraRpc {
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            client: Client::new(),
+            url: settings.zebra_rpc_url(),
+            timeout: Duration::from_secs(settings.timeouts.request_secs),
+            cookie_file: settings.zebra.cookie_file.clone(),
+            zebra_container: format!("{}-zebra", settings.container_prefix()),
+            credentials: Arc::new(OnceCell::new()),
+        }
+    }
+
+    /// POST a request, adding basic auth once Zebra has asked for it
+    async fn post(&self, body: &Value) -> Result<reqwest::Response> {
+        let request = || {
+            let request = self.client.post(&self.url).json(body).timeout(self.timeout);
+            match self.credentials.get() {
+                Some((user, password)) => request.basic_auth(user, Some(password)),
+                None => request,
+            }
+        };
+
+        let resp = request().send().await?;
+        if resp.status() != StatusCode::UNAUTHORIZED || self.credentials.initialized() {
+            return Ok(resp);
+        }
+
+        // Zebra runs with cookie auth (`zeckit up --rpc-auth`)
+        self.credentials.get_or_try_init(|| async { self.read_cookie() }).await?;
+        Ok(request().send().await?)
+    }
+
+    /// Cookie from `zebra.cookie_file`, else from the running Zebra container
+    fn read_cookie(&self) -> Result<(String, String)> {
+        let contents = match &self.cookie_file {
+            Some(path) => Some(std::fs::read_to_string(path).map_err(|e| {
+                ZecKitError::Config(format!("Could not read Zebra cookie {:?}: {}", path, e))
+            })?),
+            None => volume::read_container_file(&self.zebra_container, COOKIE_FILE)?,
+        };
+
+        contents
+            .as_deref()
+            .and_then(|c| c.trim().split_once(':'))
+            .map(|(user, password)| (user.to_string(), password.to_string()))
+            .ok_or_else(|| {
+                ZecKitError::Config(format!(
+                    "Zebra requires RPC authentication but no cookie was found in {}:{}; set zebra.cookie_file",
+                    self.zebra_container, COOKIE_FILE
+                ))
+            })
+    }
+
+    /// Call `method` and return its `result`, turning RPC error objects into errors
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let resp = self
+            .post(&json!({
+                "jsonrpc": "2.0",
+                "id": "zeckit",
+                "method": method,
+                "params": params
+            }))
+            .await?;
+
+        let status = resp.status();
+        let body: Value = resp.json().await.map_err(|e| {
+            ZecKitError::HealthCheck(format!("Invalid response to {} ({}): {}", method, status, e))
+        })?;
+
+        if let Some(error) = body.get("error").filter(|e| !e.is_null()) {
+            return Err(ZecKitError::Rpc {
+                code: error.get("code").and_then(|c| c.as_i64()).unwrap_or(0),
+                message: error
+                    .get("message")
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("unknown error")
+                    .to_string(),
+            });
+        }
+
+        body.get("result")
+            .cloned()
+            .ok_or_else(|| ZecKitError::HealthCheck(format!("No result in {} response", method)))
+    }
+
+    pub async fn get_blockchain_info(&self) -> Result<BlockchainInfo> {
+        Ok(serde_json::from_value(self.call("getblockchaininfo", json!([])).await?)?)
+    }
+
+    pub async fn get_block_count(&self) -> Result<u64> {
+        self.call("getblockcount", json!([]))
+            .await?
+            .as_u64()
+            .ok_or_else(|| ZecKitError::HealthCheck("Invalid block count response".into()))
+    }
+
+    /// Mine `count` blocks and return their hashes
+    pub async fn generate(&self, count: u32) -> Result<Vec<String>> {
+        let result = self.call("generate", json!([count])).await?;
+
+        result
+            .as_array()
+            .map(|hashes| {
+                hashes
+                    .iter()
+                    .filter_map(|h| h.as_str().map(str::to_string))
+                    .collect()
+            })
+            .ok_or_else(|| ZecKitError::HealthCheck("Invalid generate response".into()))
+    }
+
+    /// Block by hash or height (Zebra takes both as a string)
+    pub async fn get_block(&self, hash_or_height: &str) -> Result<Block> {
+        Ok(serde_json::from_value(self.call("getblock", json!([hash_or_height, 1])).await?)?)
+    }
+
+    pub async fn get_raw_transaction(&self, txid: &str) -> Result<RawTransaction> {
+        Ok(serde_json::from_value(self.call("getrawtransaction", json!([txid, 1])).await?)?)
+    }
+
+    /// Height of the block with the given hash
+    pub async fn get_block_height(&self, hash: &str) -> Result<u64> {
+        self.call("getblock", json!([hash, 1]))
+            .await?
+            .get("height")
+            .and_then(|h| h.as_u64())
+            .ok_or_else(|| ZecKitError::HealthCheck(format!("No height for block {}", hash)))
+    }
+
+    /// Confirmations of a transaction: 0 while it is in the mempool, `None`
+    /// while the node has not seen it at all
+    pub async fn get_transaction_confirmations(&self, txid: &str) -> Result<Option<u64>> {
+        match self.get_raw_transaction(txid).await {
+            Ok(raw) => Ok(Some(raw.confirmations.unwrap_or(0))),
+            Err(ZecKitError::Rpc { code: RPC_INVALID_ADDRESS_OR_KEY, .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::address;
+use crate::error::{Result, ZecKitError};
+use bip39::Mnemonic;
+use zcash_keys::keys::UnifiedSpendingKey;
+use zcash_protocol::consensus::Network;
+use zcash_transparent::address::TransparentAddress;
+use zcash_transparent::keys::{IncomingViewingKey, NonHardenedChildIndex};
+use zip32::AccountId;
+
+/// Seed the faucet falls back to when none is configured (mirrors `SeedManager`
+/// in zeckit-faucet), so every installation mines to the same wallet
+pub const DEFAULT_SEED_PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+
+/// File in the faucet-data volume where the faucet keeps the seed it was created with
+pub const SEED_FILE: &str = ".wallet_seed";
+
+/// Regtest keys use testnet's BIP-44 coin type (1), so testnet parameters
+/// derive the same keys the faucet's regtest wallet does
+const KEY_NETWORK: Network = Network::TestNetwork;
+
+/// Word counts BIP-39 allows
+pub const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// Fresh random English mnemonic of `words` words
+pub fn generate(words: usize) -> Result<String> {
+    Mnemonic::generate(words)
+        .map(|m| m.to_string())
+        .map_err(|e| ZecKitError::Config(format!("Could not generate mnemonic: {}", e)))
+}
+
+/// External transparent (P2PKH) address `index` of `account`, derived from the
+/// account's unified spending key as the faucet wallet does (`m/44'/1'/account'/0/index`)
+pub fn transparent_address(phrase: &str, passphrase: &str, account: u32, index: u32) -> Result<String> {
+    let mnemonic = Mnemonic::parse_normalized(phrase)
+        .map_err(|e| ZecKitError::Config(format!("Invalid faucet seed phrase: {}", e)))?;
+    let account = AccountId::try_from(account)
+        .map_err(|_| ZecKitError::Config(format!("Invalid account index {}", account)))?;
+    let index = NonHardenedChildIndex::from_index(index)
+        .ok_or_else(|| ZecKitError::Config(format!("Invalid address index {}", index)))?;
+
+    let usk = UnifiedSpendingKey::from_seed(&KEY_NETWORK, &mnemonic.to_seed(passphrase), account)
+        .map_err(|e| derivation_error(e.to_string()))?;
+    let address = usk
+        .transparent()
+        .to_account_pubkey()
+        .derive_external_ivk()
+        .and_then(|ivk| ivk.derive_address(index))
+        .map_err(|e| derivation_error(e.to_string()))?;
+
+    match address {
+        TransparentAddress::PublicKeyHash(hash) => Ok(address::transparent_p2pkh(&hash)),
+        TransparentAddress::ScriptHash(_) => Err(derivation_error("derived a P2SH address".into())),
+    }
+}
+
+fn derivation_error(e: String) -> ZecKitError {
+    ZecKitError::Config(format!("Could not derive faucet key: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_seed_matches_known_miner_address() {
+        assert_eq!(
+            transparent_address(DEFAULT_SEED_PHRASE, "", 0, 0).unwrap(),
+            "tmBsTi2xWTjUdEXnuTceL7fecEQKeWaPDJd"
+        );
+    }
+
+    /// Expected addresses come from an independent BIP-32 derivation; the faucet
+    /// imports a passphrase-protected seed as `UnifiedSpendingKey::from_seed` of
+    /// account 0 (see `WalletManager` in zeckit-faucet), which mines here
+    #[test]
+    fn passphrase_seed_matches_the_faucet_wallet() {
+        assert_eq!(
+            transparent_address(DEFAULT_SEED_PHRASE, "devnet", 0, 0).unwrap(),
+            "tmUdr3HZovAJiz8hHrEsZ373KywL8NHRxNy"
+        );
+        assert_eq!(
+            transparent_address(DEFAULT_SEED_PHRASE, "devnet", 0, 3).unwrap(),
+            "tmQu6nfYp8t8iiyVUXcgLsh9PbT5UyEW75F"
+        );
+        assert_eq!(
+            transparent_address(DEFAULT_SEED_PHRASE, "", 1, 0).unwrap(),
+            "tmFHMD21fowpyfY4wv1WcBHkzfhD5rW51Ds"
+        );
+    }
+
+    #[test]
+    fn passphrase_and_generated_seeds_change_the_address() {
+        let default = transparent_address(DEFAULT_SEED_PHRASE, "", 0, 0).unwrap();
+        assert_ne!(transparent_address(DEFAULT_SEED_PHRASE, "devnet", 0, 0).unwrap(), default);
+
+        let phrase = generate(24).unwrap();
+        assert_eq!(phrase.split_whitespace().count(), 24);
+        assert!(transparent_address(&phrase, "", 0, 0).unwrap().starts_with("tm"));
+    }
+
+    #[test]
+    fn rejects_invalid_phrases() {
+        assert!(transparent_address("abandon abandon", "", 0, 0).is_err());
+        assert!(transparent_address(&DEFAULT_SEED_PHRASE.replace(" art", " abandon"), "", 0, 0).is_err());
+    }
+}
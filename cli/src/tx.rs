@@ -0,0 +1,350 @@
+use crate::error::{Result, ZecKitError};
+use serde::Serialize;
+use zcash_primitives::transaction::Transaction as ZcashTransaction;
+use zcash_protocol::consensus::{BlockHeight, BranchId};
+use zcash_protocol::local_consensus::LocalNetwork;
+use zcash_protocol::value::{BalanceError, Zatoshis};
+
+/// What `zeckit tx` shows of a transaction, as parsed by zcash_primitives
+#[derive(Debug, Serialize)]
+pub struct Transaction {
+    pub version: u32,
+    pub version_group_id: u32,
+    /// Consensus branch id: encoded in v5 transactions, else the one active at
+    /// the height the transaction was decoded for
+    pub branch_id: u32,
+    pub lock_time: u32,
+    pub expiry_height: u32,
+    pub inputs: Vec<TxIn>,
+    pub outputs: Vec<TxOut>,
+    pub sapling_spends: usize,
+    pub sapling_outputs: usize,
+    /// Net zatoshis leaving the Sapling pool
+    pub sapling_value_balance: i64,
+    pub joinsplits: usize,
+    /// Net zatoshis leaving the Sprout pool through JoinSplits
+    pub sprout_value_balance: i64,
+    pub orchard_actions: usize,
+    /// Net zatoshis leaving the Orchard pool
+    pub orchard_value_balance: i64,
+    #[serde(skip)]
+    parsed: ZcashTransaction,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TxIn {
+    /// Previous transaction id, in RPC (byte-reversed) order
+    pub prev_txid: String,
+    pub prev_index: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TxOut {
+    /// Value in zatoshis
+    pub value: u64,
+    /// Hex-encoded scriptPubKey
+    pub script: String,
+}
+
+impl TxIn {
+    pub fn is_coinbase(&self) -> bool {
+        self.prev_index == u32::MAX && self.prev_txid.bytes().all(|b| b == b'0')
+    }
+}
+
+impl Transaction {
+    pub fn is_coinbase(&self) -> bool {
+        self.parsed.transparent_bundle().is_some_and(|b| b.is_coinbase())
+    }
+
+    /// Zatoshis paid in fees, given the value each transparent input spends
+    /// (in input order); `None` for coinbase or when an input value is unknown
+    pub fn fee(&self, input_values: &[Option<u64>]) -> Option<u64> {
+        if self.is_coinbase() {
+            return None;
+        }
+        let vin = self.parsed.transparent_bundle().map(|b| b.vin.as_slice()).unwrap_or_default();
+        self.parsed
+            .fee_paid(|outpoint| {
+                vin.iter()
+                    .position(|input| input.prevout() == outpoint)
+                    .and_then(|i| input_values.get(i).copied().flatten())
+                    .map(Zatoshis::from_u64)
+                    .transpose()
+            })
+            .ok()
+            .flatten()
+            .map(u64::from)
+    }
+}
+
+/// Name of the network upgrade with consensus branch id `id`
+pub fn branch_name(id: u32) -> Option<&'static str> {
+    Some(match id {
+        0x5ba8_1b19 => "Overwinter",
+        0x76b8_09bb => "Sapling",
+        0x2bb4_0e60 => "Blossom",
+        0xf5b9_230b => "Heartwood",
+        0xe9ff_75a6 => "Canopy",
+        0xc2d6_d0b4 => "NU5",
+        0xc8e7_1055 => "NU6",
+        0x4dec_4df0 => "NU6.1",
+        _ => return None,
+    })
+}
+
+/// Consensus parameters of a regtest chain, from the upgrade names and
+/// activation heights Zebra reports in `getblockchaininfo`
+pub fn regtest_network<'a>(upgrades: impl IntoIterator<Item = (&'a str, u64)>) -> LocalNetwork {
+    let mut network = LocalNetwork {
+        overwinter: None,
+        sapling: None,
+        blossom: None,
+        heartwood: None,
+        canopy: None,
+        nu5: None,
+        nu6: None,
+        nu6_1: None,
+        nu6_2: None,
+        nu6_3: None,
+    };
+    for (name, height) in upgrades {
+        let slot = match name {
+            "Overwinter" => &mut network.overwinter,
+            "Sapling" => &mut network.sapling,
+            "Blossom" => &mut network.blossom,
+            "Heartwood" => &mut network.heartwood,
+            "Canopy" => &mut network.canopy,
+            "NU5" => &mut network.nu5,
+            "NU6" => &mut network.nu6,
+            "NU6.1" => &mut network.nu6_1,
+            _ => continue,
+        };
+        *slot = u32::try_from(height).ok().map(BlockHeight::from_u32);
+    }
+    network
+}
+
+/// Decode a hex-encoded raw transaction as returned by `getrawtransaction`,
+/// under the consensus rules active on `network` at `height`
+pub fn decode_hex(raw: &str, network: &LocalNetwork, height: u64) -> Result<Transaction> {
+    let bytes = hex::decode(raw.trim()).map_err(|e| invalid(format!("not hex: {}", e)))?;
+    decode(&bytes, network, height)
+}
+
+pub fn decode(bytes: &[u8], network: &LocalNetwork, height: u64) -> Result<Transaction> {
+    let height = u32::try_from(height).map_err(|_| invalid(format!("implausible height {}", height)))?;
+    let branch = BranchId::for_height(network, BlockHeight::from_u32(height));
+
+    let mut reader = bytes;
+    let parsed = ZcashTransaction::read(&mut reader, branch).map_err(|e| invalid(e.to_string()))?;
+    if !reader.is_empty() {
+        return Err(invalid(format!("{} trailing bytes", reader.len())));
+    }
+
+    let (inputs, outputs) = match parsed.transparent_bundle() {
+        Some(bundle) => (
+            bundle
+                .vin
+                .iter()
+                .map(|input| {
+                    let mut prev_txid = *input.prevout().hash();
+                    prev_txid.reverse();
+                    TxIn {
+                        prev_txid: hex::encode(prev_txid),
+                        prev_index: input.prevout().n(),
+                    }
+                })
+                .collect(),
+            bundle
+                .vout
+                .iter()
+                .map(|output| TxOut {
+                    value: output.value().into_u64(),
+                    script: hex::encode(&output.script_pubkey().0 .0),
+                })
+                .collect(),
+        ),
+        None => (Vec::new(), Vec::new()),
+    };
+
+    let (sapling_spends, sapling_outputs, sapling_value_balance) = parsed
+        .sapling_bundle()
+        .map(|b| (b.shielded_spends().len(), b.shielded_outputs().len(), i64::from(*b.value_balance())))
+        .unwrap_or_default();
+    let (orchard_actions, orchard_value_balance) = parsed
+        .orchard_bundle()
+        .map(|b| (b.actions().len(), i64::from(*b.value_balance())))
+        .unwrap_or_default();
+    let (joinsplits, sprout_value_balance) = match parsed.sprout_bundle() {
+        Some(bundle) => (
+            bundle.joinsplits.len(),
+            bundle
+                .value_balance()
+                .map(i64::from)
+                .ok_or_else(|| invalid(BalanceError::Overflow.to_string()))?,
+        ),
+        None => (0, 0),
+    };
+
+    Ok(Transaction {
+        version: parsed.version().header() & 0x7fff_ffff,
+        version_group_id: parsed.version().version_group_id(),
+        branch_id: u32::from(parsed.consensus_branch_id()),
+        lock_time: parsed.lock_time(),
+        expiry_height: u32::from(parsed.expiry_height()),
+        inputs,
+        outputs,
+        sapling_spends,
+        sapling_outputs,
+        sapling_value_balance,
+        joinsplits,
+        sprout_value_balance,
+        orchard_actions,
+        orchard_value_balance,
+        parsed,
+    })
+}
+
+fn invalid(reason: String) -> ZecKitError {
+    ZecKitError::Decode(format!("transaction: {}", reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zcash_primitives::transaction::{Authorized, TransactionData, TxVersion};
+    use zcash_transparent::address::{Script, TransparentAddress};
+    use zcash_transparent::bundle::{self as transparent, OutPoint, TxIn as TransparentIn, TxOut as TransparentOut};
+
+    /// v4 transaction from testnet block 280003 moving 10 ZEC from a transparent
+    /// input into Sprout (zcash_primitives' read/write test vector)
+    const JOINSPLIT_V4: &[&str] = &[
+        "0400008085202f89018f642996df1e93a6d79ae5baae3493f423ca6c82e99f3e8d9524fa78bcf16167000000006b4830",
+        "45022100b65e37229707d9cd483940d2ab8bdc0b74b12dda66d02dbdf36fd383b9602a5102204be7fd7a39a4a42dff07",
+        "1a5a2bc51b492d33f0bc394bc87861e1bcaaf2bac93b01210248e78bdc18f1a83110c12e4008b764026961b168fe8d5a",
+        "8d947efe6af83cc88effffffff01f0f27018020000001976a914a284d0511d0e520d36f444a36c10bf54b4b017cd88ac",
+        "00000000d7450400000000000000000000000100ca9a3b0000000000000000000000001331a3059e66aa6ca97a62f56e",
+        "a234207568566f6971b3722ae0dd82c00399692aacb5fb12ac580ac26624a8cf0a904cd6f4bfea55625205cb58f06b1c",
+        "197423280deac74eea97598c4314d899a4fd85311e046257d2d4c297f1406cf709d92a8607f7698d45fe9f41dea3a057",
+        "1c5da5cfa78e18ebf580c36179d9d6e6320a348f146c407adab4cb310392a5f5b5ab283b78343ba91abc7c4bfe23a3db",
+        "af8037c676e595a26574b1813bc2bf2d2e911f6f3abb0ba6bcac7a2901fbdce65fb07b5636017ef14dff44cdeea73047",
+        "7294f2f8619bd3d5e6be4898bf8d39c0e0eae5a36864625206b9a8f9940bf16650def7926eb0db43b7d7615e4774cf10",
+        "9482f2e807fee6c0c884e8314c67c5d85f4c229cdeab1e964cf0c1adcb47cebfc7c067a0f3c806814a285edbb624f471",
+        "0629098944ac75e7c9cbc56bd0a029e1110eac60cb4077ebf108fe3e67cd061391e5d6916d5f41c02b8914c12cf605db",
+        "7d959226e2e8ff71263b9af4c59b0f4db315b74ca2b0b7d25213d5293954c3e51172370fb6c35abe9ce36ef253e3a72e",
+        "19dac9bd7362c44992974215c82cb90c99488dbde11963e857cea6b81b8eaae34b7cf5a97d6b60d49fdfa20f5f3c120e",
+        "f382ca2469604fb0c6842c6d4fae9661665b5cbc612cef132f88fb7da393f356e3ad13fc3557980a7734231453e44079",
+        "042fb432f55e751484d5d6d30fbc4f999013d5d4f2fb62f7144e8dcd2ae59546cc4379ad9f1859ef80dec66b1a9b0b7f",
+        "d2c47bd38302d29c31990329a895876ed1d84db757856e75ce9a1dc7c7472bc218fb8d7c7d028bb02f10efe7fe6a8c9c",
+        "e034fea66b909c8d4126251c7d6e54f4cfc778cd4f0e0bad1096176f2dd45c45cbe15e118f90ff2545f832f23698f2c9",
+        "531b52655a4c0c8953559928eedfc756c365cf929b8447dcdc7d823849e02ff68b6278d7542ce0f1070bb1ad913c1a35",
+        "3625f5d35b14cfec84a633d7fe25256dcffe92f9a6f0fe00caaaa5b39cc2ab06768a42a5b40083cea01c96b3e68d0f6a",
+        "587eaf2da6fdadc82527f186a60471ce98e27d2b11efc47998f3030a7a2e5d0b0a7eb80f6bd0e4b9c8367c6c522d9415",
+        "f8caec7b0a7318d53dce391cf7e7389c9a74aa6a4c217c288519af81ba2122ca0c5840cc02cf1bcf150cd3df33c0acfd",
+        "0053e668b926561b924098d97aaab57ee1113df966a422ef9b014617bceef05fb6468e330e2dece3f375e98ef03e5b18",
+        "a953e2301fccec86200ae432c9c12c30775437f3629714a9fabeb53289402b7fd386cef2b1146723a89d0f81651e00ca",
+        "ea2f3ac9eefefb868d85ed2354f530fe38fe3a3a6aab47d42dc21329e3ad1b9d06c0c8d6537456f54ad0453f444175d8",
+        "7ef5cdd1694662e0a1e6e3632ed7a8e76bc7b1b5a418f086d340815ec398f092e97869f5e201c22c87918f766a3532eb",
+        "9a4fc9acf196cbc2d0285119a4216d2581cd2d91bcdce868c468f6f34cf49e3a56ce249a2fd8cf36b01b0f77de722bbc",
+        "e267e3e5521688e65222235c91c263d80e28297e929d885b7b9c1a1654b2d0b87577c9a1c725f54415dc5f52dde0695f",
+        "9f6dcb4b6ee3e3ea702904c11ff92f55534c7ef98ce793d74756a45d4e320a425e982d5b372d6a8d41fb86ba51648168",
+        "32a481825c8c6ad7270969859e55d2367535060f99857065170466bdb70cb93ab2f9c0e293a0a919843bbf34c2fe61b0",
+        "c3e32aa7078e83d4c1929e1e1d86141cdeb18920910975db3a7626820599630c423ade233d5d60685524e8d8032b861b",
+        "4aad2002a8fd17c9282b825f02d353e291379ced00ebaa3c03e01d9c59f405099d1c3432bad06358d6b1942f0baf7109",
+        "98d10a22d155b0fe849952893126949ff92de3a4c2eeafdf688435e325d81c2ce008cf6c76030d4d46342ac3372c7398",
+        "6560c4ec35a6f649ef02c11936b7039bc6f5d09438dbe476251b5964b68f02eedff7a9e0ed3e3090965a22f2c552ce3b",
+        "2b474fd2fc06b50927830a05a303faffd68482d7b78538432540dd3261ab759b6582129a7f18d801c54319ca52a3c6a3",
+        "db635044d625e24038ad4277f8d5bf016035165f21b070e8169d657d6ed1fa7f8ed09b4e1d9ca2e51a24da55e43b3fca",
+        "9859b2408c26aacbad749ebe882c31e7205e638bb7e2bfc8a3f1c02c0ca7bb9daaab7fcbf845d8002c3de79924dcaadc",
+        "24bdc0082f4a6b61876f3192a881f59a682d273685d4795c9bd7cccf49de34443a9f9cb35bbf254c50611b7c1324b110",
+        "94667b6b608c39d1252cebcc4877ceea76e19b842b67f626743fab297776cc9cf79e90e8fce1001790c2e7d5c958647c",
+        "ca5d3397d20afcf29ba44f62a7c62e908d848d81a79fadbb370aba93b03e41d4bc49e299d6d33faf869f36371414ce64",
+        "6fc2ca6dcff55a6e0639d50caeb114c418c626b86715436481d1928d55a756a603e7110c3afe963c2b29a478f9d4397b",
+        "885a67b093a345796219c111b7e94db390aa4bb76b66a534e5e2679b27db5f95fd09a36b05",
+    ];
+
+    fn all_upgrades_at(height: u64) -> LocalNetwork {
+        regtest_network(["Overwinter", "Sapling", "Blossom", "Heartwood", "Canopy", "NU5", "NU6"].map(|n| (n, height)))
+    }
+
+    /// A v5 coinbase paying one P2PKH output, encoded by zcash_primitives
+    fn coinbase_v5(height: u32) -> Vec<u8> {
+        let bundle = transparent::Bundle {
+            // Coinbase input whose script pushes the height
+            vin: vec![TransparentIn::from_parts(
+                OutPoint::NULL,
+                Script::read(&[2, 1, height as u8][..]).unwrap(),
+                u32::MAX,
+            )],
+            vout: vec![TransparentOut::new(
+                Zatoshis::const_from_u64(625_000_000),
+                TransparentAddress::PublicKeyHash([7; 20]).script().into(),
+            )],
+            authorization: transparent::Authorized,
+        };
+        let tx = TransactionData::<Authorized>::from_parts(
+            TxVersion::V5,
+            BranchId::Nu6,
+            0,
+            BlockHeight::from_u32(height + 40),
+            Some(bundle),
+            None,
+            None,
+            None,
+        )
+        .freeze()
+        .unwrap();
+
+        let mut bytes = Vec::new();
+        tx.write(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn decodes_v5_coinbase() {
+        let tx = decode(&coinbase_v5(100), &all_upgrades_at(1), 100).unwrap();
+        assert_eq!(tx.version, 5);
+        assert_eq!(branch_name(tx.branch_id), Some("NU6"));
+        assert_eq!(tx.expiry_height, 140);
+        assert!(tx.is_coinbase());
+        assert!(tx.inputs[0].is_coinbase());
+        assert_eq!(tx.outputs[0].value, 625_000_000);
+        assert_eq!(tx.outputs[0].script, format!("76a914{}88ac", "07".repeat(20)));
+        assert_eq!((tx.sapling_spends, tx.sapling_outputs, tx.orchard_actions), (0, 0, 0));
+        assert_eq!(tx.fee(&[None]), None);
+    }
+
+    #[test]
+    fn decodes_v4_under_the_branch_active_at_its_height() {
+        let raw = JOINSPLIT_V4.concat();
+        let tx = decode_hex(&raw, &all_upgrades_at(1), 280_003).unwrap();
+        assert_eq!(tx.version, 4);
+        assert_eq!(branch_name(tx.branch_id), Some("NU6"));
+        assert_eq!(tx.inputs.len(), 1);
+        assert!(!tx.is_coinbase());
+        assert_eq!((tx.joinsplits, tx.sprout_value_balance), (1, -1_000_000_000));
+
+        let canopy_only = regtest_network(["Overwinter", "Sapling", "Blossom", "Heartwood", "Canopy"].map(|n| (n, 1)));
+        let tx = decode_hex(&raw, &canopy_only, 280_003).unwrap();
+        assert_eq!(branch_name(tx.branch_id), Some("Canopy"));
+
+        // Inputs plus the value leaving the shielded pools, minus the outputs
+        let outputs: u64 = tx.outputs.iter().map(|o| o.value).sum();
+        let input = outputs + 10_000 + 1_000_000_000;
+        assert_eq!(tx.fee(&[Some(input)]), Some(10_000));
+        assert_eq!(tx.fee(&[None]), None);
+    }
+
+    #[test]
+    fn rejects_truncated_and_invalid_transactions() {
+        let network = all_upgrades_at(1);
+        assert!(decode_hex("0400008085202f89", &network, 10).is_err());
+        assert!(decode(&1u32.to_le_bytes(), &network, 10).is_err());
+        assert!(decode_hex("zz", &network, 10).is_err());
+
+        let mut trailing = coinbase_v5(100);
+        trailing.push(0);
+        assert!(decode(&trailing, &network, 100).is_err());
+    }
+}
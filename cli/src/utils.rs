@@ -1,7 +1,47 @@
+use crate::error::Result;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::Duration;
 
-/// Check if Docker is installed and running
-#[allow(dead_code)]
+/// Files that mark a ZecKit project root
+const PROJECT_MARKERS: &[&str] = &["zeckit.toml", "docker-compose.yml"];
+
+/// Locate the project root: the nearest directory, from the current one
+/// upward, holding a `zeckit.toml` or `docker-compose.yml`. Falls back to the
+/// current directory, e.g. before `zeckit init`.
+pub fn project_dir() -> Result<PathBuf> {
+    let current_dir = std::env::current_dir()?;
+    Ok(find_project_dir(&current_dir).unwrap_or(current_dir))
+}
+
+fn find_project_dir(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| PROJECT_MARKERS.iter().any(|marker| dir.join(marker).is_file()))
+        .map(Path::to_path_buf)
+}
+
+/// Parse a human duration such as `60s`, `2m`, `500ms` or `1h`; a bare number is seconds
+pub fn parse_duration(s: &str) -> std::result::Result<Duration, String> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+
+    let value: u64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration '{}' (e.g. 60s, 2m, 500ms)", s))?;
+
+    match unit {
+        "ms" => Ok(Duration::from_millis(value)),
+        "" | "s" => Ok(Duration::from_secs(value)),
+        "m" => Ok(Duration::from_secs(value * 60)),
+        "h" => Ok(Duration::from_secs(value * 3600)),
+        _ => Err(format!("invalid duration unit '{}' (use ms, s, m or h)", unit)),
+    }
+}
+
+/// Check if the Docker CLI is installed
 pub fn check_docker() -> bool {
     Command::new("docker")
         .arg("--version")
@@ -10,8 +50,16 @@ pub fn check_docker() -> bool {
         .unwrap_or(false)
 }
 
+/// Check if the Docker daemon is reachable
+pub fn check_docker_daemon() -> bool {
+    Command::new("docker")
+        .arg("info")
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
 /// Check if Docker Compose is available
-#[allow(dead_code)]
 pub fn check_docker_compose() -> bool {
     Command::new("docker")
         .arg("compose")
@@ -21,6 +69,24 @@ pub fn check_docker_compose() -> bool {
         .unwrap_or(false)
 }
 
+/// Check that nothing is listening on `host:port`
+pub fn port_is_free(host: IpAddr, port: u16) -> bool {
+    std::net::TcpListener::bind((host, port)).is_ok()
+}
+
+/// Bytes available to unprivileged users on the filesystem holding `path`
+pub fn free_disk_space(path: &Path) -> Option<u64> {
+    let output = Command::new("df").arg("-Pk").arg(path).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // Second line: filesystem, 1024-blocks, used, available, capacity, mount
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let available: u64 = stdout.lines().nth(1)?.split_whitespace().nth(3)?.parse().ok()?;
+    Some(available * 1024)
+}
+
 /// Print a formatted banner
 #[allow(dead_code)]
 pub fn print_banner(title: &str) {
@@ -31,7 +97,6 @@ pub fn print_banner(title: &str) {
 }
 
 /// Format bytes for display
-#[allow(dead_code)]
 pub fn format_bytes(bytes: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = KB * 1024;
@@ -46,4 +111,32 @@ pub fn format_bytes(bytes: u64) -> String {
     } else {
         format!("{} B", bytes)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("60s"), Ok(Duration::from_secs(60)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("45"), Ok(Duration::from_secs(45)));
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration("10 days").is_err());
+    }
+
+    #[test]
+    fn finds_nearest_project_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let nested = root.path().join("app/src/bin");
+        std::fs::create_dir_all(&nested).unwrap();
+        assert_eq!(find_project_dir(&nested), None);
+
+        std::fs::write(root.path().join("docker-compose.yml"), "").unwrap();
+        assert_eq!(find_project_dir(&nested), Some(root.path().to_path_buf()));
+
+        std::fs::write(root.path().join("app/zeckit.toml"), "").unwrap();
+        assert_eq!(find_project_dir(&nested), Some(root.path().join("app")));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::error::{Result, ZecKitError};
+use crate::rpc::{FaucetApi, ZebraRpc};
+use std::future::Future;
+use tokio::time::{sleep, Duration, Instant};
+
+/// How often conditions are re-checked
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Poll `condition` until it yields a value or `timeout` passes.
+///
+/// `Ok(None)` and errors both mean "not yet"; the last error is reported if
+/// the deadline is missed, so a service that never comes up says why.
+pub async fn until<T, F, Fut>(what: &str, timeout: Duration, mut condition: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Option<T>>>,
+{
+    let deadline = Instant::now() + timeout;
+    let mut last_error = None;
+
+    loop {
+        match condition().await {
+            Ok(Some(value)) => return Ok(value),
+            Ok(None) => {}
+            Err(e) => last_error = Some(e),
+        }
+
+        if Instant::now() + POLL_INTERVAL > deadline {
+            let reason = last_error.map(|e| format!(": {}", e)).unwrap_or_default();
+            return Err(ZecKitError::ServiceNotReady(format!(
+                "{} (gave up after {}s){}",
+                what,
+                timeout.as_secs(),
+                reason
+            )));
+        }
+        sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Sync the faucet wallet until it has scanned Zebra's current tip; returns that height
+pub async fn wallet_synced(faucet: &FaucetApi, zebra: &ZebraRpc, timeout: Duration) -> Result<u64> {
+    let tip = zebra.get_block_count().await?;
+
+    until(&format!("faucet wallet sync to height {}", tip), timeout, || async move {
+        // Faucets without `synced_height` only report that sync_and_await returned
+        let synced = faucet.sync().await?.synced_height.unwrap_or(tip);
+        Ok((synced >= tip).then_some(synced))
+    })
+    .await
+}
+
+/// Wait for `txid` to be mined, generating a block whenever it is in the mempool;
+/// returns the height of the block it was mined in. Nothing is mined while the
+/// node has not seen the broadcast yet.
+pub async fn transaction_mined(zebra: &ZebraRpc, txid: &str, timeout: Duration) -> Result<u64> {
+    until(&format!("transaction {} to be mined", txid), timeout, || async move {
+        match zebra.get_transaction_confirmations(txid).await? {
+            Some(0) => {
+                zebra.generate(1).await?;
+                Ok(None)
+            }
+            Some(_) => Ok(zebra.get_raw_transaction(txid).await?.mined_height()),
+            None => Ok(None),
+        }
+    })
+    .await
+}
+
+/// Wait for Zebra's tip to reach `height`, calling `progress` with each height seen
+pub async fn chain_height(
+    zebra: &ZebraRpc,
+    height: u64,
+    timeout: Duration,
+    progress: impl Fn(u64),
+) -> Result<u64> {
+    let progress = &progress;
+    until(&format!("chain height {}", height), timeout, || async move {
+        let current = zebra.get_block_count().await?;
+        progress(current);
+        Ok((current >= height).then_some(current))
+    })
+    .await
+}
@@ -6,19 +6,27 @@ echo "🔧 Initializing Lightwalletd..."
 # Configuration
 ZEBRA_RPC_HOST=${ZEBRA_RPC_HOST:-zebra}
 ZEBRA_RPC_PORT=${ZEBRA_RPC_PORT:-8232}
+ZEBRA_RPC_COOKIE_FILE=${ZEBRA_RPC_COOKIE_FILE:-/var/zebra-cookie/.cookie}
 LWD_GRPC_BIND=${LWD_GRPC_BIND:-0.0.0.0:9067}
 
 echo "Configuration:"
 echo "  Zebra RPC:  ${ZEBRA_RPC_HOST}:${ZEBRA_RPC_PORT}"
 echo "  gRPC Bind:  ${LWD_GRPC_BIND}"
 
+# Zebra's RPC cookie, present when it runs with cookie auth (`zeckit up --rpc-auth`)
+AUTH=()
+if [ -f "${ZEBRA_RPC_COOKIE_FILE}" ]; then
+    echo "  RPC auth:   cookie (${ZEBRA_RPC_COOKIE_FILE})"
+    AUTH=(-u "$(cat "${ZEBRA_RPC_COOKIE_FILE}")")
+fi
+
 # Wait for Zebra
 echo " Waiting for Zebra RPC..."
 MAX_ATTEMPTS=60
 ATTEMPT=0
 
 while [ $ATTEMPT -lt $MAX_ATTEMPTS ]; do
-    if curl -s \
+    if curl -s "${AUTH[@]}" \
         -X POST \
         -H "Content-Type: application/json" \
         -d '{"jsonrpc":"2.0","id":"health","method":"getblockcount","params":[]}' \
@@ -36,7 +44,7 @@ if [ $ATTEMPT -eq $MAX_ATTEMPTS ]; then
 fi
 
 # Get block count
-BLOCK_COUNT=$(curl -s \
+BLOCK_COUNT=$(curl -s "${AUTH[@]}" \
     -X POST \
     -H "Content-Type: application/json" \
     -d '{"jsonrpc":"2.0","id":"info","method":"getblockcount","params":[]}' \
@@ -48,7 +56,7 @@ echo "Current block height: ${BLOCK_COUNT}"
 echo " Waiting for at least 10 blocks to be mined..."
 while [ "${BLOCK_COUNT}" -lt "10" ]; do
     sleep 10
-    BLOCK_COUNT=$(curl -s \
+    BLOCK_COUNT=$(curl -s "${AUTH[@]}" \
         -X POST \
         -H "Content-Type: application/json" \
         -d '{"jsonrpc":"2.0","id":"info","method":"getblockcount","params":[]}' \
@@ -58,7 +66,15 @@ done
 
 echo "Zebra has ${BLOCK_COUNT} blocks!"
 
-# Start lightwalletd with RPC credentials (dummy values for Zebra which doesn't require auth)
+# RPC credentials: the cookie's user and password, or dummy values when Zebra doesn't require auth
+RPC_USER=zcash
+RPC_PASSWORD=zcash
+if [ -f "${ZEBRA_RPC_COOKIE_FILE}" ]; then
+    COOKIE=$(cat "${ZEBRA_RPC_COOKIE_FILE}")
+    RPC_USER=${COOKIE%%:*}
+    RPC_PASSWORD=${COOKIE#*:}
+fi
+
 echo "Starting lightwalletd..."
 exec lightwalletd \
     --grpc-bind-addr=${LWD_GRPC_BIND} \
@@ -67,5 +83,5 @@ exec lightwalletd \
     --no-tls-very-insecure=true \
     --rpchost=${ZEBRA_RPC_HOST} \
     --rpcport=${ZEBRA_RPC_PORT} \
-    --rpcuser=zcash \
-    --rpcpassword=zcash
\ No newline at end of file
+    --rpcuser="${RPC_USER}" \
+    --rpcpassword="${RPC_PASSWORD}"
\ No newline at end of file
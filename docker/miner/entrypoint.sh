@@ -0,0 +1,39 @@
+#!/bin/bash
+set -e
+
+echo "⛏  Initializing ZecKit background miner..."
+
+# Configuration
+ZEBRA_RPC_URL=${ZEBRA_RPC_URL:-http://zebra:8232}
+MINER_INTERVAL=${MINER_INTERVAL:-15}
+MINER_STATE_DIR=${MINER_STATE_DIR:-/var/miner}
+ZEBRA_RPC_COOKIE_FILE=${ZEBRA_RPC_COOKIE_FILE:-/var/zebra-cookie/.cookie}
+
+# `zeckit miner rate <secs>` writes this file; it wins over MINER_INTERVAL
+# and survives container restarts because it lives on the miner-data volume
+INTERVAL_FILE="${MINER_STATE_DIR}/interval"
+
+mkdir -p "${MINER_STATE_DIR}"
+
+echo "Configuration:"
+echo "  Zebra RPC:  ${ZEBRA_RPC_URL}"
+echo "  Interval:   $(cat "${INTERVAL_FILE}" 2>/dev/null || echo "${MINER_INTERVAL}")s"
+
+while true; do
+    INTERVAL=$(cat "${INTERVAL_FILE}" 2>/dev/null || echo "${MINER_INTERVAL}")
+    sleep "${INTERVAL}"
+
+    # Zebra rewrites its cookie on every start, so read it each time
+    AUTH=()
+    if [ -f "${ZEBRA_RPC_COOKIE_FILE}" ]; then
+        AUTH=(-u "$(cat "${ZEBRA_RPC_COOKIE_FILE}")")
+    fi
+
+    RESPONSE=$(curl -s --max-time 30 "${AUTH[@]}" \
+        -X POST \
+        -H "Content-Type: application/json" \
+        -d '{"jsonrpc":"2.0","id":"miner","method":"generate","params":[1]}' \
+        "${ZEBRA_RPC_URL}" || echo "request failed")
+
+    echo "$(date -u +%Y-%m-%dT%H:%M:%SZ) ${RESPONSE}"
+done
@@ -6,6 +6,7 @@ echo "🔧 Initializing Zaino Indexer..."
 # Configuration
 ZEBRA_RPC_HOST=${ZEBRA_RPC_HOST:-zebra}
 ZEBRA_RPC_PORT=${ZEBRA_RPC_PORT:-8232}
+ZEBRA_RPC_COOKIE_FILE=${ZEBRA_RPC_COOKIE_FILE:-/var/zebra-cookie/.cookie}
 ZAINO_GRPC_BIND=${ZAINO_GRPC_BIND:-0.0.0.0:9067}
 ZAINO_DATA_DIR=${ZAINO_DATA_DIR:-/var/zaino}
 
@@ -24,13 +25,20 @@ echo "  Zebra RPC:  ${ZEBRA_RPC_HOST}:${ZEBRA_RPC_PORT}"
 echo "  gRPC Bind:  ${ZAINO_GRPC_BIND}"
 echo "  Data Dir:   ${ZAINO_DATA_DIR}"
 
+# Zebra's RPC cookie, present when it runs with cookie auth (`zeckit up --rpc-auth`)
+AUTH=()
+if [ -f "${ZEBRA_RPC_COOKIE_FILE}" ]; then
+    echo "  RPC auth:   cookie (${ZEBRA_RPC_COOKIE_FILE})"
+    AUTH=(-u "$(cat "${ZEBRA_RPC_COOKIE_FILE}")")
+fi
+
 # Wait for Zebra
 echo " Waiting for Zebra RPC..."
 MAX_ATTEMPTS=60
 ATTEMPT=0
 
 while [ $ATTEMPT -lt $MAX_ATTEMPTS ]; do
-    if curl -s \
+    if curl -s "${AUTH[@]}" \
         -X POST \
         -H "Content-Type: application/json" \
         -d '{"jsonrpc":"2.0","id":"health","method":"getblockcount","params":[]}' \
@@ -48,7 +56,7 @@ if [ $ATTEMPT -eq $MAX_ATTEMPTS ]; then
 fi
 
 # Get block count
-BLOCK_COUNT=$(curl -s \
+BLOCK_COUNT=$(curl -s "${AUTH[@]}" \
     -X POST \
     -H "Content-Type: application/json" \
     -d '{"jsonrpc":"2.0","id":"info","method":"getblockcount","params":[]}' \
@@ -60,7 +68,7 @@ echo "Current block height: ${BLOCK_COUNT}"
 echo " Waiting for at least 10 blocks to be mined..."
 while [ "${BLOCK_COUNT}" -lt "10" ]; do
     sleep 10
-    BLOCK_COUNT=$(curl -s \
+    BLOCK_COUNT=$(curl -s "${AUTH[@]}" \
         -X POST \
         -H "Content-Type: application/json" \
         -d '{"jsonrpc":"2.0","id":"info","method":"getblockcount","params":[]}' \
@@ -85,6 +93,9 @@ echo "insecure = true" >> ${ZAINO_DATA_DIR}/zainod/zindexer.toml
 echo "" >> ${ZAINO_DATA_DIR}/zainod/zindexer.toml
 echo "[validator_settings]" >> ${ZAINO_DATA_DIR}/zainod/zindexer.toml
 echo "validator_jsonrpc_listen_address = \"${ZEBRA_RPC_HOST}:${ZEBRA_RPC_PORT}\"" >> ${ZAINO_DATA_DIR}/zainod/zindexer.toml
+if [ -f "${ZEBRA_RPC_COOKIE_FILE}" ]; then
+    echo "validator_cookie_path = \"${ZEBRA_RPC_COOKIE_FILE}\"" >> ${ZAINO_DATA_DIR}/zainod/zindexer.toml
+fi
 echo "" >> ${ZAINO_DATA_DIR}/zainod/zindexer.toml
 echo "[storage.database]" >> ${ZAINO_DATA_DIR}/zainod/zindexer.toml
 echo "path = \"${ZAINO_DATA_DIR}\"" >> ${ZAINO_DATA_DIR}/zainod/zindexer.toml
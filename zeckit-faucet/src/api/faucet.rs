@@ -4,6 +4,7 @@ use serde_json::json;
 use zcash_address::ZcashAddress;
 use crate::AppState;
 use crate::error::FaucetError;
+use crate::validation::zebra_rpc::validate_address_via_zebra;
 
 #[derive(Debug, Deserialize)]
 pub struct FaucetRequest {
@@ -45,8 +46,14 @@ pub(crate) async fn request_funds(
     State(state): State<AppState>,
     Json(payload): Json<FaucetRequest>,
 ) -> Result<Json<FaucetResponse>, FaucetError> {
-    // Validate address
+    // Validate address locally, then against the node
     let validated_address = validate_address(&payload.address)?;
+    let validated_address = validate_address_via_zebra(
+        &validated_address,
+        &state.config.zebra_rpc_url,
+        state.config.zebra_rpc_cookie_file.as_deref(),
+    )
+    .await?;
     
     // Get and validate amount
     let amount = payload.amount.unwrap_or(state.config.faucet_amount_default);
@@ -29,7 +29,9 @@ pub(crate) async fn get_stats(
         "faucet_address": address,
         "current_balance": balance.total_zec(),
         "orchard_balance": balance.orchard_zec(),
+        "sapling_balance": balance.sapling_zec(),
         "transparent_balance": balance.transparent_zec(),
+        "synced_height": wallet.synced_height(),
         "total_requests": tx_count,
         "total_sent": total_sent,
         "last_request": last_request,
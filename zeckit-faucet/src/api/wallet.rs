@@ -29,6 +29,7 @@ pub(crate) async fn sync_wallet(
     
     Ok(Json(json!({
         "status": "synced",
+        "synced_height": wallet.synced_height(),
         "message": "Wallet synced with blockchain"
     })))
 }
@@ -6,9 +6,163 @@ pub struct Config {
     pub zingo_data_dir: PathBuf,
     pub lightwalletd_uri: String,
     pub zebra_rpc_url: String,
+    /// Zebra's RPC cookie; requests use HTTP basic auth when the file exists
+    pub zebra_rpc_cookie_file: Option<PathBuf>,
     pub faucet_amount_min: f64,
     pub faucet_amount_max: f64,
     pub faucet_amount_default: f64,
+    pub activation_heights: ActivationHeights,
+    pub seed: SeedConfig,
+}
+
+/// Docker secrets checked when no mnemonic / passphrase is passed in the environment
+const MNEMONIC_SECRET: &str = "/run/secrets/faucet_mnemonic";
+const PASSPHRASE_SECRET: &str = "/run/secrets/faucet_passphrase";
+
+/// Wallet identity; without a mnemonic the faucet uses the shared regtest seed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeedConfig {
+    #[serde(skip_serializing)]
+    pub mnemonic: Option<String>,
+    #[serde(skip_serializing)]
+    pub passphrase: Option<String>,
+    /// Block height the wallet starts scanning from
+    pub birthday: u32,
+    /// Number of accounts created in a new wallet
+    pub accounts: u32,
+}
+
+impl Default for SeedConfig {
+    fn default() -> Self {
+        Self {
+            mnemonic: None,
+            passphrase: None,
+            birthday: 0,
+            accounts: 1,
+        }
+    }
+}
+
+impl SeedConfig {
+    /// `FAUCET_MNEMONIC`, else the file named by `FAUCET_MNEMONIC_FILE`, else
+    /// the `faucet_mnemonic` Docker secret (and likewise for the passphrase)
+    pub fn load() -> anyhow::Result<Self> {
+        let accounts = std::env::var("FAUCET_ACCOUNTS")
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| s.trim().parse::<u32>())
+            .transpose()
+            .map_err(|_| anyhow::anyhow!("FAUCET_ACCOUNTS must be a positive number"))?
+            .unwrap_or(1);
+        if accounts == 0 {
+            anyhow::bail!("FAUCET_ACCOUNTS must be at least 1");
+        }
+
+        Ok(Self {
+            mnemonic: read_secret("FAUCET_MNEMONIC", "FAUCET_MNEMONIC_FILE", MNEMONIC_SECRET)?,
+            passphrase: read_secret("FAUCET_PASSPHRASE", "FAUCET_PASSPHRASE_FILE", PASSPHRASE_SECRET)?,
+            birthday: std::env::var("FAUCET_BIRTHDAY")
+                .ok()
+                .filter(|s| !s.trim().is_empty())
+                .map(|s| s.trim().parse())
+                .transpose()
+                .map_err(|_| anyhow::anyhow!("FAUCET_BIRTHDAY must be a block height"))?
+                .unwrap_or(0),
+            accounts,
+        })
+    }
+}
+
+fn read_secret(var: &str, file_var: &str, secret: &str) -> anyhow::Result<Option<String>> {
+    if let Ok(value) = std::env::var(var) {
+        if !value.trim().is_empty() {
+            return Ok(Some(value.trim().to_string()));
+        }
+    }
+
+    let path = match std::env::var(file_var) {
+        Ok(path) if !path.trim().is_empty() => PathBuf::from(path.trim()),
+        _ if std::path::Path::new(secret).exists() => PathBuf::from(secret),
+        _ => return Ok(None),
+    };
+
+    let value = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+    Ok(Some(value.trim().to_string()).filter(|v| !v.is_empty()))
+}
+
+/// Regtest network upgrade heights; must match the Zebra node the wallet syncs from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivationHeights {
+    pub overwinter: Option<u32>,
+    pub sapling: Option<u32>,
+    pub blossom: Option<u32>,
+    pub heartwood: Option<u32>,
+    pub canopy: Option<u32>,
+    pub nu5: Option<u32>,
+    pub nu6: Option<u32>,
+    pub nu6_1: Option<u32>,
+    pub nu7: Option<u32>,
+}
+
+impl Default for ActivationHeights {
+    fn default() -> Self {
+        Self {
+            overwinter: Some(1),
+            sapling: Some(1),
+            blossom: Some(1),
+            heartwood: Some(1),
+            canopy: Some(1),
+            nu5: Some(1),
+            nu6: None,
+            nu6_1: None,
+            nu7: None,
+        }
+    }
+}
+
+impl ActivationHeights {
+    /// Parse the `overwinter=1,...,nu6=150` list the CLI passes in `ACTIVATION_HEIGHTS`.
+    /// Upgrades missing from the list are inactive.
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        let mut heights = Self {
+            overwinter: None,
+            sapling: None,
+            blossom: None,
+            heartwood: None,
+            canopy: None,
+            nu5: None,
+            nu6: None,
+            nu6_1: None,
+            nu7: None,
+        };
+
+        for entry in value.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (key, height) = entry
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Invalid activation height '{}'", entry))?;
+            let height: u32 = height
+                .trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid activation height '{}'", entry))?;
+
+            let slot = match key.trim() {
+                "overwinter" => &mut heights.overwinter,
+                "sapling" => &mut heights.sapling,
+                "blossom" => &mut heights.blossom,
+                "heartwood" => &mut heights.heartwood,
+                "canopy" => &mut heights.canopy,
+                "nu5" => &mut heights.nu5,
+                "nu6" => &mut heights.nu6,
+                "nu6_1" | "nu6.1" => &mut heights.nu6_1,
+                "nu7" => &mut heights.nu7,
+                other => anyhow::bail!("Unknown network upgrade '{}'", other),
+            };
+            *slot = Some(height);
+        }
+
+        Ok(heights)
+    }
 }
 
 impl Config {
@@ -21,6 +175,10 @@ impl Config {
                 .unwrap_or_else(|_| "http://zaino:9067".to_string()),
             zebra_rpc_url: std::env::var("ZEBRA_RPC_URL")
                 .unwrap_or_else(|_| "http://zebra:8232".to_string()),
+            zebra_rpc_cookie_file: std::env::var("ZEBRA_RPC_COOKIE_FILE")
+                .ok()
+                .filter(|s| !s.trim().is_empty())
+                .map(|s| PathBuf::from(s.trim())),
             faucet_amount_min: std::env::var("FAUCET_AMOUNT_MIN")
                 .ok()
                 .and_then(|s| s.parse().ok())
@@ -33,6 +191,11 @@ impl Config {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(10.0),
+            activation_heights: match std::env::var("ACTIVATION_HEIGHTS") {
+                Ok(value) if !value.trim().is_empty() => ActivationHeights::parse(&value)?,
+                _ => ActivationHeights::default(),
+            },
+            seed: SeedConfig::load()?,
         })
     }
 }
\ No newline at end of file
@@ -9,7 +9,6 @@ use tower_http::cors::CorsLayer;
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use tokio::time::{sleep, Duration};
-use tonic::transport::Channel;
 use zcash_protocol::value::Zatoshis;
 
 mod config;
@@ -30,34 +29,17 @@ pub struct AppState {
 
 /// Health check for Zaino - uses lightweight gRPC ping instead of full sync
 async fn wait_for_zaino(uri: &str, max_attempts: u32) -> anyhow::Result<u64> {
-    use zcash_client_backend::proto::service::compact_tx_streamer_client::CompactTxStreamerClient;
-    use zcash_client_backend::proto::service::ChainSpec;
-    
     info!(" Waiting for Zaino at {} to be ready...", uri);
     
     for attempt in 1..=max_attempts {
-        let ping_result = tokio::time::timeout(
-            Duration::from_secs(5),
-            async {
-                let channel = Channel::from_shared(uri.to_string())?
-                    .connect_timeout(Duration::from_secs(3))
-                    .connect()
-                    .await?;
-                
-                let mut client = CompactTxStreamerClient::new(channel);
-                let response = client.get_latest_block(ChainSpec {}).await?;
-                let block = response.into_inner();
-                
-                Ok::<u64, anyhow::Error>(block.height)
-            }
-        ).await;
+        let ping_result = wallet::indexer::latest_block_height(uri).await;
         
         match ping_result {
-            Ok(Ok(height)) => {
+            Ok(height) => {
                 info!(" Zaino ready at block height {} (took {}s)", height, attempt * 5);
                 return Ok(height);
             }
-            Ok(Err(e)) => {
+            Err(e) => {
                 if attempt % 6 == 0 {  // Log every 30 seconds
                     info!(" Still waiting for Zaino... ({}s elapsed)", attempt * 5);
                     tracing::debug!("Zaino error: {}", e);
@@ -65,13 +47,6 @@ async fn wait_for_zaino(uri: &str, max_attempts: u32) -> anyhow::Result<u64> {
                     tracing::debug!("Zaino not ready (attempt {}): {}", attempt, e);
                 }
             }
-            Err(_) => {
-                if attempt % 6 == 0 {
-                    info!(" Still waiting for Zaino... ({}s elapsed) - connection timeout", attempt * 5);
-                } else {
-                    tracing::debug!("Zaino connection timeout (attempt {})", attempt);
-                }
-            }
         }
         
         if attempt < max_attempts {
@@ -106,6 +81,7 @@ async fn main() -> anyhow::Result<()> {
     info!("  Backend: {}", if config.lightwalletd_uri.contains("lightwalletd") { "lightwalletd" } else { "zaino" }); 
     info!("  LightwalletD URI: {}", config.lightwalletd_uri);
     info!("  Data dir: {}", config.zingo_data_dir.display());
+    info!("  Activation heights: {:?}", config.activation_heights);
 
     // ═══════════════════════════════════════════════════════════
     // STEP 3: Wait for Zaino Backend
@@ -120,6 +96,8 @@ async fn main() -> anyhow::Result<()> {
     let wallet = WalletManager::new(
         config.zingo_data_dir.clone(),
         config.lightwalletd_uri.clone(),
+        &config.activation_heights,
+        &config.seed,
     ).await?;
 
     let wallet = Arc::new(RwLock::new(wallet));
@@ -14,9 +14,12 @@ mod tests {
             zingo_data_dir: temp_dir.path().to_path_buf(),
             lightwalletd_uri: server_uri,
             zebra_rpc_url: "http://localhost:8232".to_string(),
+            zebra_rpc_cookie_file: None,
             faucet_amount_min: 0.01,
             faucet_amount_max: 100.0,
             faucet_amount_default: 10.0,
+            activation_heights: Default::default(),
+            seed: Default::default(),
         };
 
         assert_eq!(config.faucet_amount_min, 0.01);
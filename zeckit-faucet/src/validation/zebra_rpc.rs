@@ -1,12 +1,9 @@
 use crate::error::FaucetError;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
 use tracing::debug;
 
-// Note: These structs and the validate_address_via_zebra function are currently unused
-// but kept for future Zebra RPC integration when direct node validation is needed.
-// They provide an alternative to the current zcash_address parsing validation.
-#[allow(dead_code)]
 #[derive(Debug, Serialize)]
 struct ZebraRpcRequest {
     jsonrpc: String,
@@ -15,35 +12,48 @@ struct ZebraRpcRequest {
     params: Vec<String>,
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct ZebraRpcResponse {
     result: Option<ValidateAddressResult>,
     error: Option<RpcError>,
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct RpcError {
     message: String,
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct ValidateAddressResult {
     isvalid: bool,
     address: Option<String>,
 }
 
-/// Validates a Zcash address via Zebra RPC node.
-/// 
-/// Note: Currently unused - kept for future integration with Zebra node validation.
-/// This provides an alternative to the current zcash_address parsing approach,
-/// allowing validation directly against a running Zebra node for additional checks.
-#[allow(dead_code)]
+/// User and password from Zebra's RPC cookie (`__cookie__:<password>`), or
+/// `None` if the file doesn't exist because Zebra runs without cookie auth.
+/// Zebra rewrites the cookie on every start, so it is read per request.
+fn read_cookie(path: &Path) -> Result<Option<(String, String)>, FaucetError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let cookie = std::fs::read_to_string(path)
+        .map_err(|e| FaucetError::Validation(format!("Failed to read Zebra cookie {}: {}", path.display(), e)))?;
+    let (user, password) = cookie
+        .trim()
+        .split_once(':')
+        .ok_or_else(|| FaucetError::Validation(format!("Malformed Zebra cookie {}", path.display())))?;
+
+    Ok(Some((user.to_string(), password.to_string())))
+}
+
+/// Validates a Zcash address via Zebra RPC node, authenticating with the RPC
+/// cookie when `cookie_file` exists. `z_validateaddress` accepts transparent,
+/// Sapling and unified addresses alike.
 pub async fn validate_address_via_zebra(
     address: &str,
     zebra_rpc_url: &str,
+    cookie_file: Option<&Path>,
 ) -> Result<String, FaucetError> {
     debug!("Validating address via Zebra RPC: {}", &address[..12]);
 
@@ -52,13 +62,16 @@ pub async fn validate_address_via_zebra(
     let request = ZebraRpcRequest {
         jsonrpc: "2.0".to_string(),
         id: "validate_addr".to_string(),
-        method: "validateaddress".to_string(),
+        method: "z_validateaddress".to_string(),
         params: vec![address.to_string()],
     };
 
-    let response = client
-        .post(zebra_rpc_url)
-        .json(&request)
+    let mut rpc_request = client.post(zebra_rpc_url).json(&request);
+    if let Some((user, password)) = cookie_file.map(read_cookie).transpose()?.flatten() {
+        rpc_request = rpc_request.basic_auth(user, Some(password));
+    }
+
+    let response = rpc_request
         .send()
         .await
         .map_err(|e| FaucetError::Validation(format!("RPC request failed: {}", e)))?;
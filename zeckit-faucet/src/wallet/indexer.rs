@@ -0,0 +1,20 @@
+use std::time::Duration;
+use tonic::transport::Channel;
+use zcash_client_backend::proto::service::compact_tx_streamer_client::CompactTxStreamerClient;
+use zcash_client_backend::proto::service::ChainSpec;
+
+/// Height of the indexer's (lightwalletd / Zaino) latest block, via gRPC `GetLatestBlock`
+pub async fn latest_block_height(uri: &str) -> anyhow::Result<u64> {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        let channel = Channel::from_shared(uri.to_string())?
+            .connect_timeout(Duration::from_secs(3))
+            .connect()
+            .await?;
+
+        let mut client = CompactTxStreamerClient::new(channel);
+        let block = client.get_latest_block(ChainSpec {}).await?.into_inner();
+        Ok(block.height)
+    })
+    .await
+    .map_err(|_| anyhow::anyhow!("Timed out waiting for {}", uri))?
+}
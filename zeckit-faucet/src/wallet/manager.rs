@@ -1,3 +1,4 @@
+use crate::config::{ActivationHeights, SeedConfig};
 use crate::error::FaucetError;
 use crate::wallet::history::{TransactionHistory, TransactionRecord};
 use std::path::PathBuf;
@@ -12,8 +13,11 @@ use zcash_primitives::consensus::BlockHeight;
 use zebra_chain::parameters::testnet::ConfiguredActivationHeights;
 use zcash_primitives::memo::MemoBytes;
 use zcash_client_backend::zip321::{TransactionRequest, Payment};
+use crate::wallet::indexer;
 use crate::wallet::seed::SeedManager;
 use zcash_protocol::value::Zatoshis;
+use zcash_keys::keys::{Era, UnifiedSpendingKey};
+use std::num::NonZeroU32;
 
 #[derive(Debug, Clone)]
 pub struct Balance {
@@ -48,12 +52,18 @@ impl Balance {
 pub struct WalletManager {
     client: LightClient,
     history: TransactionHistory,
+    server_uri: String,
+    /// Indexer tip the last successful sync started from; the wallet has
+    /// scanned at least this far
+    synced_height: Option<u64>,
 }
 
 impl WalletManager {
     pub async fn new(
         data_dir: PathBuf,
         server_uri: String,
+        heights: &ActivationHeights,
+        seed: &SeedConfig,
     ) -> Result<Self, FaucetError> {
         info!("Initializing ZingoLib LightClient");
         
@@ -69,19 +79,19 @@ impl WalletManager {
         // NEW: Get or create deterministic seed
         // ============================================================
         let seed_manager = SeedManager::new(&data_dir);
-        let seed_phrase = seed_manager.get_or_create_seed()?;
+        let seed_phrase = seed_manager.get_or_create_seed(seed.mnemonic.as_deref())?;
         
         let activation_heights = ConfiguredActivationHeights {
             before_overwinter: Some(1),
-            overwinter: Some(1),
-            sapling: Some(1),
-            blossom: Some(1),
-            heartwood: Some(1),
-            canopy: Some(1),
-            nu5: Some(1),
-            nu6: None,      // ← Changed to None
-            nu6_1: None,    // ← Changed to None
-            nu7: None,      // ← Changed to None
+            overwinter: heights.overwinter,
+            sapling: heights.sapling,
+            blossom: heights.blossom,
+            heartwood: heights.heartwood,
+            canopy: heights.canopy,
+            nu5: heights.nu5,
+            nu6: heights.nu6,
+            nu6_1: heights.nu6_1,
+            nu7: heights.nu7,
         };
         let chain_type = ChainType::Regtest(activation_heights);
         
@@ -102,19 +112,45 @@ impl WalletManager {
             })?
         } else {
             info!("Creating new wallet with deterministic seed");
+            info!("  Birthday: {}, accounts: {}", seed.birthday, seed.accounts);
             
             // Convert seed phrase string to Mnemonic
             let mnemonic = bip0039::Mnemonic::from_phrase(seed_phrase)
                 .map_err(|e| FaucetError::Wallet(format!("Invalid mnemonic phrase: {}", e)))?;
             
+            let no_of_accounts = NonZeroU32::new(seed.accounts).ok_or_else(|| {
+                FaucetError::Wallet("Account count must be at least 1".to_string())
+            })?;
+            
+            // The wallet derives keys from a mnemonic without a passphrase, so a
+            // passphrase-protected seed is imported as a spending key instead
+            let wallet_base = match seed.passphrase.as_deref() {
+                Some(passphrase) => {
+                    if seed.accounts > 1 {
+                        return Err(FaucetError::Wallet(
+                            "A seed passphrase supports a single account".to_string(),
+                        ));
+                    }
+                    let usk = UnifiedSpendingKey::from_seed(
+                        &chain_type,
+                        &mnemonic.to_seed(passphrase),
+                        zip32::AccountId::ZERO,
+                    ).map_err(|e| {
+                        FaucetError::Wallet(format!("Failed to derive spending key: {:?}", e))
+                    })?;
+                    WalletBase::Usk(usk.to_bytes(Era::Orchard))
+                }
+                None => WalletBase::Mnemonic {
+                    mnemonic,
+                    no_of_accounts,
+                },
+            };
+            
             // Create wallet from mnemonic
             let wallet = LightWallet::new(
                 chain_type,
-                WalletBase::Mnemonic {
-                    mnemonic,
-                    no_of_accounts: std::num::NonZeroU32::new(1).unwrap(),
-                },
-                BlockHeight::from_u32(0),
+                wallet_base,
+                BlockHeight::from_u32(seed.birthday),
                 config.wallet_settings.clone(),
             ).map_err(|e| {
                 FaucetError::Wallet(format!("Failed to create wallet: {}", e))
@@ -130,7 +166,12 @@ impl WalletManager {
 
         info!("Wallet initialized successfully (sync not started)");
 
-        Ok(Self { client, history })
+        Ok(Self {
+            client,
+            history,
+            server_uri,
+            synced_height: None,
+        })
     }
 
     pub async fn get_unified_address(&self) -> Result<String, FaucetError> {
@@ -280,12 +321,24 @@ impl WalletManager {
     }
 
     pub async fn sync(&mut self) -> Result<(), FaucetError> {
+        // Read the tip first: sync_and_await scans at least up to it
+        let tip = indexer::latest_block_height(&self.server_uri).await.ok();
+        
         self.client.sync_and_await().await.map_err(|e| {
             FaucetError::Wallet(format!("Sync failed: {}", e))
         })?;
+        
+        if tip.is_some() {
+            self.synced_height = tip;
+        }
         Ok(())
     }
 
+    /// Height the wallet is known to be synced to, if it has synced yet
+    pub fn synced_height(&self) -> Option<u64> {
+        self.synced_height
+    }
+
     pub fn get_transaction_history(&self, limit: usize) -> Vec<TransactionRecord> {
         self.history.get_recent(limit)
     }
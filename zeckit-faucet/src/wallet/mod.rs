@@ -1,5 +1,6 @@
 pub mod manager;
 pub mod history;
+pub mod indexer;
 pub mod seed;
 
 pub use manager::WalletManager;
@@ -14,8 +14,36 @@ impl SeedManager {
         }
     }
     
-    /// Get or create deterministic seed for this ZecKit installation
-    pub fn get_or_create_seed(&self) -> Result<String, FaucetError> {
+    /// Get or create deterministic seed for this ZecKit installation.
+    ///
+    /// A `configured` mnemonic wins over the shared default, but cannot replace
+    /// the seed an existing wallet was created from.
+    pub fn get_or_create_seed(&self, configured: Option<&str>) -> Result<String, FaucetError> {
+        if let Some(phrase) = configured {
+            let phrase = normalize(phrase);
+            bip0039::Mnemonic::<bip0039::English>::from_phrase(phrase.as_str())
+                .map_err(|e| FaucetError::Wallet(format!("Invalid configured mnemonic: {}", e)))?;
+
+            if self.seed_file.exists() {
+                let saved = fs::read_to_string(&self.seed_file)
+                    .map_err(|e| FaucetError::Wallet(format!("Failed to read seed file: {}", e)))?;
+                if normalize(&saved) != phrase {
+                    return Err(FaucetError::Wallet(
+                        "Configured mnemonic differs from the one this wallet was created with; \
+                         remove the faucet data volume (zeckit up --fresh) to switch seeds"
+                            .to_string(),
+                    ));
+                }
+            } else {
+                // Saved so `zeckit up` can derive the mining address from the volume
+                fs::write(&self.seed_file, &phrase)
+                    .map_err(|e| FaucetError::Wallet(format!("Failed to write seed file: {}", e)))?;
+            }
+
+            info!("Using configured wallet seed");
+            return Ok(phrase);
+        }
+
         // If seed file exists, use it
         if self.seed_file.exists() {
             info!("Loading existing wallet seed from {:?}", self.seed_file);
@@ -28,7 +56,9 @@ impl SeedManager {
         info!("Generating new deterministic seed for this ZecKit installation");
         
         // Use a default regtest seed (same for all installations)
-        // This ensures everyone gets the same wallet addresses for testing
+        // This ensures everyone gets the same wallet addresses for testing.
+        // `zeckit up` derives Zebra's miner address from this phrase (or the
+        // saved seed file), so keep it in sync with the CLI's DEFAULT_SEED_PHRASE.
         let seed_phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
         
         info!("⚠️  Using default regtest seed - same wallet for all ZecKit installations");
@@ -42,4 +72,9 @@ impl SeedManager {
         
         Ok(seed_phrase.to_string())
     }
-}
\ No newline at end of file
+}
+
+/// Collapse whitespace so a phrase read from a file or env var compares equal
+fn normalize(phrase: &str) -> String {
+    phrase.split_whitespace().collect::<Vec<_>>().join(" ")
+}